@@ -0,0 +1,75 @@
+const { PublicKey, SystemProgram } = require('@solana/web3.js');
+const BN = require('bn.js');
+
+// Must match MAX_BATCH_SIZE in programs/ticket-core/src/constants.rs
+const MAX_BATCH_SIZE = 10;
+
+function vaultPda(programId, event) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), event.toBuffer()],
+        programId
+    );
+    return pda;
+}
+
+function purchaseRecordPda(programId, event, wallet) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("purchase"), event.toBuffer(), wallet.toBuffer()],
+        programId
+    );
+    return pda;
+}
+
+function ticketPda(programId, event, ticketId) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("ticket"), event.toBuffer(), new BN(ticketId).toArrayLike(Buffer, "le", 8)],
+        programId
+    );
+    return pda;
+}
+
+/**
+ * Mints `quantity` tickets of one tier, splitting into as many `batch_mint`
+ * transactions as needed. `recipients` is optional; when given it must hold
+ * one wallet per ticket. Every ticket counts against the buyer's
+ * max_tickets_per_wallet, including those sent to other recipients.
+ */
+async function batchMint(program, { event, buyer, tierIndex, quantity, recipients = [] }) {
+    if (recipients.length && recipients.length !== quantity) {
+        throw new Error("recipients must be empty or contain one wallet per ticket");
+    }
+
+    const signatures = [];
+    for (let offset = 0; offset < quantity; offset += MAX_BATCH_SIZE) {
+        const count = Math.min(MAX_BATCH_SIZE, quantity - offset);
+        const chunkRecipients = recipients.slice(offset, offset + count);
+
+        // Re-read the counter each time: other buyers may have minted in between
+        const eventAccount = await program.account.event.fetch(event);
+        const firstId = eventAccount.nextTicketId.toNumber();
+        const tickets = Array.from({ length: count }, (_, i) => ({
+            pubkey: ticketPda(program.programId, event, firstId + i),
+            isWritable: true,
+            isSigner: false,
+        }));
+
+        const signature = await program.methods
+            .batchMint(tierIndex, count, chunkRecipients)
+            .accounts({
+                buyer,
+                event,
+                purchaseRecord: purchaseRecordPda(program.programId, event, buyer),
+                vault: vaultPda(program.programId, event),
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts(tickets)
+            .rpc();
+
+        console.log(`✅ Minted tickets ${firstId}-${firstId + count - 1}: ${signature}`);
+        signatures.push(signature);
+    }
+
+    return signatures;
+}
+
+module.exports = { batchMint, ticketPda, vaultPda, purchaseRecordPda, MAX_BATCH_SIZE };
@@ -12,6 +12,22 @@ const os = require('os');
 const BN = require('bn.js');
 require('dotenv').config();
 
+// Events are numbered per organizer: [b"event", organizer PDA, event_id]
+async function deriveNextEventPDA(connection, programId, authority) {
+    const [organizerPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("organizer"), authority.toBuffer()],
+        programId
+    );
+    // event_count follows the discriminator and authority; 0 until registered
+    const organizerInfo = await connection.getAccountInfo(organizerPDA);
+    const eventId = organizerInfo ? new BN(organizerInfo.data.subarray(40, 48), 'le') : new BN(0);
+    const [eventPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("event"), organizerPDA.toBuffer(), eventId.toArrayLike(Buffer, 'le', 8)],
+        programId
+    );
+    return { organizerPDA, eventPDA, eventId };
+}
+
 // Simple serialization for our instruction
 function serializeCreateEventInstruction(name, venue, eventDate, totalTickets, generalPrice, vipPrice) {
     // This is a simplified version - in production you'd use Borsh
@@ -59,14 +75,11 @@ async function createEvent() {
     const generalPrice = 100000000; // 0.1 SOL
     const vipPrice = 300000000; // 0.3 SOL
     
-    // Derive PDA
-    const [eventPDA, bump] = PublicKey.findProgramAddressSync(
-        [Buffer.from("event"), Buffer.from(eventName)],
-        programId
-    );
+    // Derive PDAs (register_organizer must have run once for this wallet)
+    const { organizerPDA, eventPDA, eventId } = await deriveNextEventPDA(connection, programId, wallet.publicKey);
     
     console.log("Creating event:", eventName);
-    console.log("Event PDA:", eventPDA.toString());
+    console.log("Event PDA:", eventPDA.toString(), "(event_id", eventId.toString() + ")");
     
     // Build instruction
     const instructionData = serializeCreateEventInstruction(
@@ -81,6 +94,7 @@ async function createEvent() {
     const instruction = new TransactionInstruction({
         keys: [
             { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+            { pubkey: organizerPDA, isSigner: false, isWritable: true },
             { pubkey: eventPDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         ],
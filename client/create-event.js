@@ -1,8 +1,25 @@
 const { Connection, Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } = require('@solana/web3.js');
 const fs = require('fs');
 const os = require('os');
+const BN = require('bn.js');
 require('dotenv').config();
 
+// Events are numbered per organizer: [b"event", organizer PDA, event_id]
+async function deriveNextEventPDA(connection, programId, authority) {
+    const [organizerPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("organizer"), authority.toBuffer()],
+        programId
+    );
+    // event_count follows the discriminator and authority; 0 until registered
+    const organizerInfo = await connection.getAccountInfo(organizerPDA);
+    const eventId = organizerInfo ? new BN(organizerInfo.data.subarray(40, 48), 'le') : new BN(0);
+    const [eventPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("event"), organizerPDA.toBuffer(), eventId.toArrayLike(Buffer, 'le', 8)],
+        programId
+    );
+    return { organizerPDA, eventPDA, eventId };
+}
+
 async function main() {
     console.log("🎫 TicketToken Event Creator\n");
     
@@ -40,11 +57,8 @@ async function main() {
     console.log("  GA Price:", generalPrice / 1e9, "SOL");
     console.log("  VIP Price:", vipPrice / 1e9, "SOL");
     
-    // Derive the event PDA
-    const [eventPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("event"), Buffer.from(eventName)],
-        programId
-    );
+    // Derive the event PDA from this wallet's organizer account
+    const { eventPDA } = await deriveNextEventPDA(connection, programId, wallet.publicKey);
     console.log("\n📍 Event Account:", eventPDA.toString());
     
     // NOTE: To actually create the event, we'd need to:
@@ -15,13 +15,17 @@ async function testProgram() {
         console.log("💾 Program Size:", programInfo.data.length, "bytes");
         console.log("👤 Owner:", programInfo.owner.toString());
         console.log("\n📝 Available Functions:");
+        console.log("  • register_organizer(treasury, refund_policy) / update_organizer()");
         console.log("  • create_event(name, venue, date, tickets, prices)");
         console.log("  • update_event(venue, date, prices)");
+        console.log("  • open_sales() / pause_sales() / resume_sales()");
+        console.log("  • start_event() / end_event()");
+        console.log("  • pause_event() / unpause_event()");
         console.log("  • cancel_event()");
         console.log("  • mint_ticket(tier)");
         console.log("  • batch_mint(tier, quantity)");
-        console.log("  • reserve_tickets(quantity)");
-        console.log("  • mint_whitelist(tier, proof)");
+        console.log("  • reserve_tickets(tier, quantity, recipients)");
+        console.log("  • mint_whitelist(tier, allocation, proof)");
         console.log("\n🚀 Your smart contract is live on Solana Devnet!");
         console.log("🔗 Explorer: https://explorer.solana.com/address/" + programId.toString() + "?cluster=devnet");
     } else {
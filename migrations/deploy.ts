@@ -8,5 +8,20 @@ module.exports = async function (provider: anchor.AnchorProvider) {
   // Configure client to use the provider.
   anchor.setProvider(provider);
 
-  // Add your deploy script here.
+  // Create the global ProgramState; the wallet must be the upgrade authority
+  // and becomes the program authority.
+  const program = anchor.workspace.TicketCore;
+  const platformFeeBasisPoints = 250; // 2.5%
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+  await program.methods
+    .initializeProgram(provider.wallet.publicKey, platformFeeBasisPoints)
+    .accounts({
+      authority: provider.wallet.publicKey,
+      program: program.programId,
+      programData,
+    })
+    .rpc();
 };
@@ -1,13 +1,33 @@
 //! Global constants for the ticket system
 pub const MAX_EVENT_NAME_LEN: usize = 100;
 pub const MAX_VENUE_NAME_LEN: usize = 100;
-pub const MAX_BATCH_SIZE: u32 = 100;
-pub const MAX_GATE_STAFF: usize = 50;
+pub const MAX_EVENT_DESCRIPTION_LEN: usize = 200;
+pub const MAX_BATCH_SIZE: u32 = 10; // Tickets per transaction (account + compute limits)
+pub const MAX_GATE_ID_LEN: usize = 32;
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+pub const MAX_TIERS: usize = 10;
+pub const MAX_TIER_NAME_LEN: usize = 32;
+pub const MAX_PAYEES: usize = 8;
+pub const MAX_SECTIONS: usize = 20;
+pub const MAX_SECTION_NAME_LEN: usize = 16;
+pub const MAX_SERIES_EVENTS: usize = 16; // Must fit the SeriesPass entry bitmap (u32)
+pub const MAX_SERIES_NAME_LEN: usize = 100;
 pub const MAX_TRANSFER_HISTORY: usize = 100;
 pub const REFUND_WINDOW_SECONDS: i64 = 86400; // 24 hours
-pub const SURGE_PRICING_THRESHOLD: f64 = 0.8; // 80% capacity
+pub const ENTRY_GRACE_SECONDS: i64 = 3600; // Gates stay open an hour after the end
+pub const HOLD_DURATION_SECONDS: i64 = 600; // 10 minutes to complete checkout
+pub const SURGE_PRICING_THRESHOLD_BPS: u16 = 8_000; // 80% capacity
+pub const MAX_PRICE_STEPS: usize = 8;
+pub const MAX_PRICE_MULTIPLIER_BPS: u32 = 100_000; // 10x
 pub const MAX_REFUND_REASON_LEN: usize = 200;
+pub const MAX_CANCELLATION_REASON_LEN: usize = 200;
+pub const MAX_REFUND_TIERS: usize = 8;
 pub const PLATFORM_FEE_BASIS_POINTS: u16 = 250; // 2.5%
+pub const MAX_PLATFORM_FEE_BASIS_POINTS: u16 = 1_000; // 10%
+pub const DEFAULT_TIMELOCK_DELAY_SECONDS: i64 = 172_800; // 48 hours
+pub const MIN_TIMELOCK_DELAY_SECONDS: i64 = 3_600; // 1 hour
+pub const MAX_TIMELOCK_DELAY_SECONDS: i64 = 2_592_000; // 30 days
+pub const TIMELOCK_GRACE_PERIOD_SECONDS: i64 = 1_209_600; // 14 days to execute after eta
 
 // String length limits
 pub const MAX_NAME_LENGTH: usize = 100;
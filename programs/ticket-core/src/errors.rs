@@ -51,6 +51,48 @@ pub enum TicketError {
     #[msg("E1016: Invalid event dates")]
     InvalidEventDates = 1016,
 
+    #[msg("E1017: Event has not ended yet")]
+    EventNotEnded = 1017,
+
+    #[msg("E1018: Event already belongs to a series")]
+    EventAlreadyInSeries = 1018,
+
+    #[msg("E1019: Series has too many events")]
+    TooManySeriesEvents = 1019,
+
+    #[msg("E1020: Event is not part of this series")]
+    EventNotInSeries = 1020,
+
+    #[msg("E1021: Event status does not allow this action")]
+    InvalidEventStatus = 1021,
+
+    #[msg("E1022: Invalid event status transition")]
+    InvalidStatusTransition = 1022,
+
+    #[msg("E1023: Event has not started yet")]
+    EventNotStarted = 1023,
+
+    #[msg("E1024: Invalid refund policy")]
+    InvalidRefundPolicy = 1024,
+
+    #[msg("E1025: Event date is in the past")]
+    EventInPast = 1025,
+
+    #[msg("E1026: End time must be after start time")]
+    InvalidDateRange = 1026,
+
+    #[msg("E1027: Text contains invalid characters")]
+    InvalidCharacters = 1027,
+
+    #[msg("E1028: Per-wallet ticket limit must be at least 1")]
+    InvalidWalletLimit = 1028,
+
+    #[msg("E1029: Series events are fixed once passes are sold")]
+    SeriesLocked = 1029,
+
+    #[msg("E1030: Cancellation reason too long (max 200 characters)")]
+    CancellationReasonTooLong = 1030,
+
     // Ticket Errors (2000-2099)
     #[msg("E2001: Invalid ticket price (must be greater than 0)")]
     InvalidTicketPrice = 2001,
@@ -82,6 +124,87 @@ pub enum TicketError {
     #[msg("E2010: Invalid validation type")]
     InvalidValidationType = 2010,
 
+    #[msg("E2011: Ticket tier sold out")]
+    TierSoldOut = 2011,
+
+    #[msg("E2012: Too many ticket tiers")]
+    TooManyTiers = 2012,
+
+    #[msg("E2013: Invalid tier name (1-32 characters)")]
+    InvalidTierName = 2013,
+
+    #[msg("E2014: Tier supply exceeds event capacity")]
+    TierSupplyExceedsCapacity = 2014,
+
+    #[msg("E2015: Invalid tier price bounds")]
+    InvalidTierPriceBounds = 2015,
+
+    #[msg("E2016: Ticket tier is not on sale")]
+    TierNotOnSale = 2016,
+
+    #[msg("E2017: Ticket tier has been closed")]
+    TierClosed = 2017,
+
+    #[msg("E2018: Tier supply cannot drop below tickets already minted")]
+    TierSupplyBelowMinted = 2018,
+
+    #[msg("E2019: Batch size too large")]
+    BatchSizeTooLarge = 2019,
+
+    #[msg("E2020: Recipient count does not match quantity")]
+    InvalidRecipientCount = 2020,
+
+    #[msg("E2021: Invalid pricing curve")]
+    InvalidPricingCurve = 2021,
+
+    #[msg("E2022: Hold has not expired yet")]
+    HoldNotExpired = 2022,
+
+    #[msg("E2023: Not a comp ticket")]
+    NotCompTicket = 2023,
+
+    #[msg("E2024: Comp ticket already assigned")]
+    CompAlreadyAssigned = 2024,
+
+    #[msg("E2025: Seat already taken")]
+    SeatTaken = 2025,
+
+    #[msg("E2026: Seat does not exist for this tier")]
+    InvalidSeat = 2026,
+
+    #[msg("E2027: Tier has reserved seating; mint with a seat")]
+    SeatRequired = 2027,
+
+    #[msg("E2028: Invalid seating map")]
+    InvalidSeatingMap = 2028,
+
+    #[msg("E2029: Series passes sold out")]
+    SeriesPassSoldOut = 2029,
+
+    #[msg("E2030: Ticket has already been used")]
+    TicketAlreadyUsed = 2030,
+
+    #[msg("E2031: Ticket has not been validated for entry")]
+    TicketNotValidated = 2031,
+
+    #[msg("E2032: Ticket is already frozen")]
+    TicketAlreadyFrozen = 2032,
+
+    #[msg("E2033: Ticket is not frozen")]
+    TicketNotFrozen = 2033,
+
+    #[msg("E2034: Ticket has already been validated for entry")]
+    AlreadyValidatedForEntry = 2034,
+
+    #[msg("E2035: Signer does not own this ticket")]
+    NotTicketOwner = 2035,
+
+    #[msg("E2036: Price outside the allowed range")]
+    PriceOutOfRange = 2036,
+
+    #[msg("E2037: Ticket has been refunded or is otherwise no longer valid")]
+    TicketNotValid = 2037,
+
     // Transfer Errors (3000-3099)
     #[msg("E3001: Transfer not allowed for this ticket")]
     TransferNotAllowed = 3001,
@@ -98,6 +221,27 @@ pub enum TicketError {
     #[msg("E3005: Cannot delegate to self")]
     SelfDelegationNotAllowed = 3005,
 
+    #[msg("E3006: Transfers are not allowed for this event")]
+    TransfersNotAllowed = 3006,
+
+    #[msg("E3007: Transfer window has closed")]
+    TransferWindowClosed = 3007,
+
+    #[msg("E3008: Invalid delegate authority")]
+    InvalidDelegateAuthority = 3008,
+
+    #[msg("E3009: Delegate transfer already claimed")]
+    DelegateAlreadyClaimed = 3009,
+
+    #[msg("E3010: Delegate transfer has expired")]
+    DelegateExpired = 3010,
+
+    #[msg("E3011: Email hash does not match")]
+    InvalidEmailHash = 3011,
+
+    #[msg("E3012: Invalid claim code")]
+    InvalidClaimCode = 3012,
+
     // Access Control Errors (4000-4099)
     #[msg("E4001: Unauthorized access")]
     Unauthorized = 4001,
@@ -114,6 +258,72 @@ pub enum TicketError {
     #[msg("E4005: Invalid compliance level")]
     InvalidComplianceLevel = 4005,
 
+    #[msg("E4006: Invalid merkle proof")]
+    InvalidMerkleProof = 4006,
+
+    #[msg("E4007: Whitelist is not active")]
+    WhitelistInactive = 4007,
+
+    #[msg("E4008: Whitelist presale has ended")]
+    WhitelistExpired = 4008,
+
+    #[msg("E4009: Whitelist allocation exceeded")]
+    WhitelistAllocationExceeded = 4009,
+
+    #[msg("E4010: No matching authority transfer is pending")]
+    NoPendingAuthority = 4010,
+
+    #[msg("E4011: Invalid pending authority")]
+    InvalidPendingAuthority = 4011,
+
+    #[msg("E4012: Not enough multisig approvals")]
+    InsufficientSigners = 4012,
+
+    #[msg("E4013: Duplicate signer")]
+    DuplicateSigner = 4013,
+
+    #[msg("E4014: Signer is not a multisig member")]
+    NotMultisigMember = 4014,
+
+    #[msg("E4015: Invalid multisig members or threshold")]
+    InvalidMultisigConfig = 4015,
+
+    #[msg("E4016: Proposal already executed")]
+    ProposalAlreadyExecuted = 4016,
+
+    #[msg("E4017: This change must be queued with queue_action")]
+    TimelockRequired = 4017,
+
+    #[msg("E4018: Timelock delay has not elapsed")]
+    TimelockNotElapsed = 4018,
+
+    #[msg("E4019: Invalid timelock delay")]
+    InvalidTimelockDelay = 4019,
+
+    #[msg("E4020: Signer may not validate tickets for this event")]
+    UnauthorizedValidator = 4020,
+
+    #[msg("E4021: Signer may not freeze tickets for this event")]
+    UnauthorizedFreezer = 4021,
+
+    #[msg("E4022: Signer may not unfreeze tickets for this event")]
+    UnauthorizedUnfreezer = 4022,
+
+    #[msg("E4023: Signer may not burn tickets for this event")]
+    UnauthorizedBurner = 4023,
+
+    #[msg("E4024: Staff role has expired")]
+    RoleExpired = 4024,
+
+    #[msg("E4025: Ticket or gate is outside the staff role's scope")]
+    OutsideRoleScope = 4025,
+
+    #[msg("E4026: Invalid role permissions or scope")]
+    InvalidPermissions = 4026,
+
+    #[msg("E4027: Queued action expired; cancel it and queue it again")]
+    TimelockActionExpired = 4027,
+
     // Financial Errors (5000-5099)
     #[msg("E5001: Insufficient funds")]
     InsufficientFunds = 5001,
@@ -136,14 +346,32 @@ pub enum TicketError {
     #[msg("E5007: Already refunded")]
     AlreadyRefunded = 5007,
 
+    #[msg("E5008: Revenue split must list unique payees summing to 10,000 basis points")]
+    InvalidRevenueSplit = 5008,
+
+    #[msg("E5009: Revenue split is locked once sales have started")]
+    RevenueSplitLocked = 5009,
+
+    #[msg("E5010: Discount cannot exceed 10,000 basis points")]
+    InvalidDiscount = 5010,
+
+    #[msg("E5011: Platform fee exceeds the maximum")]
+    FeeTooHigh = 5011,
+
+    #[msg("E5012: Ticket is not eligible for a refund")]
+    RefundNotEligible = 5012,
+
+    #[msg("E5013: Refund window has closed")]
+    RefundWindowClosed = 5013,
+
     // System Errors (6000-6099)
     #[msg("E6001: System is paused")]
     SystemPaused = 6001,
 
-    #[msg("E6002: System is already paused")]
+    #[msg("E6002: Already paused")]
     AlreadyPaused = 6002,
 
-    #[msg("E6003: System is not paused")]
+    #[msg("E6003: Not paused")]
     NotPaused = 6003,
 
     #[msg("E6004: Invalid freeze reason")]
@@ -155,6 +383,21 @@ pub enum TicketError {
     #[msg("E6006: Invalid account")]
     InvalidAccount = 6006,
 
+    #[msg("E6007: Arithmetic underflow")]
+    ArithmeticUnderflow = 6007,
+
+    #[msg("E6008: Event is paused")]
+    EventPaused = 6008,
+
+    #[msg("E6009: Invalid pause scope")]
+    InvalidPauseScope = 6009,
+
+    #[msg("E6010: Pause expiry must be in the future")]
+    InvalidPauseExpiry = 6010,
+
+    #[msg("E6011: Rate limit exceeded")]
+    RateLimitExceeded = 6011,
+
     // Metadata Errors (7000-7099)
     #[msg("E7001: Invalid metadata")]
     InvalidMetadata = 7001,
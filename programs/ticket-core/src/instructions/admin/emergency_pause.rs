@@ -1,6 +1,7 @@
 use crate::state::program_state::ProgramState;
 use anchor_lang::prelude::*;
 use crate::errors::TicketError;
+use crate::traits::Pausable;
 
 #[derive(Accounts)]
 pub struct EmergencyPause<'info> {
@@ -14,16 +15,40 @@ pub struct EmergencyPause<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
-    let program_state = &mut ctx.accounts.program_state;
-    
-    require!(!program_state.paused, TicketError::AlreadyPaused);
-    
-    program_state.paused = true;
-    program_state.paused_at = Clock::get()?.unix_timestamp;
+/// Pauses the `PauseScope` classes in `scope` program-wide, e.g. only
+/// TRANSFER during a marketplace exploit while gates stay open. The pause
+/// lifts itself at `expires_at` if one is given. `reason` is an incident code
+/// for indexers; the program does not interpret it.
+pub fn emergency_pause(
+    ctx: Context<EmergencyPause>,
+    scope: u8,
+    expires_at: Option<i64>,
+    reason: u16,
+) -> Result<()> {
+    pause_program(
+        &mut ctx.accounts.program_state,
+        ctx.accounts.authority.key(),
+        scope,
+        expires_at,
+        reason,
+    )
+}
+
+pub(crate) fn pause_program(
+    program_state: &mut Account<ProgramState>,
+    authority: Pubkey,
+    scope: u8,
+    expires_at: Option<i64>,
+    reason: u16,
+) -> Result<()> {
+    program_state.pause(scope, expires_at)?;
     
     emit!(EmergencyPauseEvent {
-        authority: ctx.accounts.authority.key(),
+        authority,
+        scope,
+        paused_scopes: program_state.pause_state.scopes,
+        expires_at,
+        reason,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
@@ -33,5 +58,9 @@ pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
 #[event]
 pub struct EmergencyPauseEvent {
     pub authority: Pubkey,
+    pub scope: u8,                          // Scopes added by this call
+    pub paused_scopes: u8,                  // All scopes now paused
+    pub expires_at: Option<i64>,            // Expiry given for `scope`
+    pub reason: u16,
     pub timestamp: i64,
 }
\ No newline at end of file
@@ -1,17 +1,24 @@
 use crate::state::program_state::ProgramState;
 use anchor_lang::prelude::*;
 use crate::errors::TicketError;
+use crate::traits::Pausable;
 
-pub fn emergency_unpause(ctx: Context<EmergencyUnpause>) -> Result<()> {
-    let program_state = &mut ctx.accounts.program_state;
-    
-    require!(program_state.paused, TicketError::NotPaused);
-    
-    program_state.paused = false;
-    program_state.paused_at = 0;
+/// Lifts the pause on `scope`; other paused scopes stay paused.
+pub fn emergency_unpause(ctx: Context<EmergencyUnpause>, scope: u8) -> Result<()> {
+    unpause_program(&mut ctx.accounts.program_state, ctx.accounts.authority.key(), scope)
+}
+
+pub(crate) fn unpause_program(
+    program_state: &mut Account<ProgramState>,
+    authority: Pubkey,
+    scope: u8,
+) -> Result<()> {
+    program_state.unpause(scope)?;
     
     emit!(EmergencyUnpauseEvent {
-        authority: ctx.accounts.authority.key(),
+        authority,
+        scope,
+        paused_scopes: program_state.pause_state.scopes,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
@@ -33,5 +40,7 @@ pub struct EmergencyUnpause<'info> {
 #[event]
 pub struct EmergencyUnpauseEvent {
     pub authority: Pubkey,
+    pub scope: u8,
+    pub paused_scopes: u8,                  // Scopes still paused
     pub timestamp: i64,
 }
\ No newline at end of file
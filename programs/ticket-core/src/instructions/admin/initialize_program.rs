@@ -0,0 +1,66 @@
+use crate::state::program_state::ProgramState;
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::program::TicketCore;
+use crate::constants::{DEFAULT_TIMELOCK_DELAY_SECONDS, MAX_PLATFORM_FEE_BASIS_POINTS};
+
+/// Creates the singleton `ProgramState`. Only the program's upgrade authority
+/// may call it, so nobody can front-run the deployer; it becomes the program
+/// authority. Can only run once per deployment.
+pub fn initialize_program(
+    ctx: Context<InitializeProgram>,
+    fee_recipient: Pubkey,
+    platform_fee_basis_points: u16,
+) -> Result<()> {
+    require!(
+        platform_fee_basis_points <= MAX_PLATFORM_FEE_BASIS_POINTS,
+        TicketError::FeeTooHigh
+    );
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.authority = ctx.accounts.authority.key();
+    program_state.fee_recipient = fee_recipient;
+    program_state.platform_fee_basis_points = platform_fee_basis_points;
+    program_state.bump = ctx.bumps.program_state;
+    program_state.timelock_delay = DEFAULT_TIMELOCK_DELAY_SECONDS;
+
+    emit!(ProgramInitializedEvent {
+        authority: program_state.authority,
+        fee_recipient,
+        platform_fee_basis_points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeProgram<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProgramState::LEN,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ TicketError::InvalidAccount)]
+    pub program: Program<'info, TicketCore>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ TicketError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ProgramInitializedEvent {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub platform_fee_basis_points: u16,
+    pub timestamp: i64,
+}
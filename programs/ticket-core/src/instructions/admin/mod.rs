@@ -1,9 +1,19 @@
+pub mod initialize_program;
+pub mod update_config;
 pub mod emergency_pause;
 pub mod emergency_unpause;
 pub mod set_compliance;
 pub mod update_authorities;
+pub mod timelock;
+pub mod withdraw_fees;
+pub mod verify_organizer;
 
+pub use initialize_program::*;
+pub use update_config::*;
 pub use emergency_pause::*;
 pub use emergency_unpause::*;
 pub use set_compliance::*;
 pub use update_authorities::*;
+pub use timelock::*;
+pub use withdraw_fees::*;
+pub use verify_organizer::*;
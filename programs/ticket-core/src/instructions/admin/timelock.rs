@@ -0,0 +1,223 @@
+use crate::state::program_state::ProgramState;
+use crate::state::{QueuedAction, TimelockedAction};
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+use crate::utils::math::safe_add;
+use super::update_authorities::set_pending_authority;
+use super::update_config::ConfigUpdatedEvent;
+
+/// Queues a `ProgramState` change behind `timelock_delay`. Until the delay
+/// has passed the change is visible on chain and the authority can cancel
+/// it; after that anyone can execute it.
+pub fn queue_action(ctx: Context<QueueAction>, action: TimelockedAction) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let queued_action_key = ctx.accounts.queued_action.key();
+    queue(
+        &mut ctx.accounts.program_state,
+        queued_action_key,
+        &mut ctx.accounts.queued_action,
+        action,
+        authority,
+        authority,
+        ctx.bumps.queued_action,
+    )
+}
+
+pub fn cancel_queued_action(ctx: Context<CancelQueuedAction>) -> Result<()> {
+    emit_cancelled(
+        &ctx.accounts.queued_action,
+        ctx.accounts.queued_action.key(),
+        ctx.accounts.authority.key(),
+    )
+}
+
+/// Applies a queued change once its eta has passed. Actions queued by an
+/// earlier authority, or not executed within the grace period after the eta,
+/// are stale and can only be cancelled.
+pub fn execute_queued_action(ctx: Context<ExecuteQueuedAction>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let queued_action = &ctx.accounts.queued_action;
+    let now = Clock::get()?.unix_timestamp;
+
+    queued_action.check_executable(program_state.authority, now)?;
+
+    match queued_action.action.clone() {
+        TimelockedAction::UpdateFee { platform_fee_basis_points } => {
+            program_state.platform_fee_basis_points = platform_fee_basis_points;
+
+            emit!(ConfigUpdatedEvent {
+                authority: queued_action.queued_by,
+                fee_recipient: program_state.fee_recipient,
+                platform_fee_basis_points,
+                timestamp: now,
+            });
+        }
+        TimelockedAction::ProposeAuthority { new_authority } => {
+            set_pending_authority(program_state, new_authority)?;
+        }
+        TimelockedAction::SetTimelockDelay { delay } => {
+            program_state.timelock_delay = delay;
+        }
+    }
+
+    emit!(QueuedActionExecutedEvent {
+        queued_action: queued_action.key(),
+        action_id: queued_action.action_id,
+        action: queued_action.action.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn queue(
+    program_state: &mut ProgramState,
+    queued_action_key: Pubkey,
+    queued_action: &mut QueuedAction,
+    action: TimelockedAction,
+    queued_by: Pubkey,
+    payer: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    action.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    queued_action.action_id = program_state.queued_action_count;
+    queued_action.action = action;
+    queued_action.queued_by = queued_by;
+    queued_action.payer = payer;
+    queued_action.queued_at = now;
+    queued_action.eta = now
+        .checked_add(program_state.timelock_delay)
+        .ok_or(TicketError::ArithmeticOverflow)?;
+    queued_action.bump = bump;
+
+    program_state.queued_action_count = safe_add(program_state.queued_action_count, 1)?;
+
+    emit!(ActionQueuedEvent {
+        queued_action: queued_action_key,
+        action_id: queued_action.action_id,
+        action: queued_action.action.clone(),
+        queued_by,
+        eta: queued_action.eta,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn emit_cancelled(
+    queued_action: &QueuedAction,
+    queued_action_key: Pubkey,
+    cancelled_by: Pubkey,
+) -> Result<()> {
+    emit!(QueuedActionCancelledEvent {
+        queued_action: queued_action_key,
+        action_id: queued_action.action_id,
+        cancelled_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = QueuedAction::LEN,
+        seeds = [b"queued_action", program_state.queued_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// No pause check: cancelling a queued change must always be possible
+#[derive(Accounts)]
+pub struct CancelQueuedAction<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ TicketError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ TicketError::InvalidAccount
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Receives the rent, must match queued_action.payer
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteQueuedAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ TicketError::InvalidAccount,
+        seeds = [b"queued_action", queued_action.action_id.to_le_bytes().as_ref()],
+        bump = queued_action.bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Receives the rent, must match queued_action.payer
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+}
+
+#[event]
+pub struct ActionQueuedEvent {
+    pub queued_action: Pubkey,
+    pub action_id: u64,
+    pub action: TimelockedAction,
+    pub queued_by: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QueuedActionCancelledEvent {
+    pub queued_action: Pubkey,
+    pub action_id: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QueuedActionExecutedEvent {
+    pub queued_action: Pubkey,
+    pub action_id: u64,
+    pub action: TimelockedAction,
+    pub timestamp: i64,
+}
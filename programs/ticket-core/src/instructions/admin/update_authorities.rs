@@ -1,24 +1,135 @@
 use crate::state::program_state::ProgramState;
 use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
 use anchor_lang::prelude::*;
 
-pub fn update_authorities(_ctx: Context<UpdateAuthorities>) -> Result<()> {
-    // TODO: Implement authority update logic
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    take_authority(&mut ctx.accounts.program_state, ctx.accounts.new_authority.key())
+}
+
+/// First step of a program authority handover, run by a `ProposeAuthority`
+/// queued action once its timelock has passed. Nothing changes until the
+/// proposed key signs `accept_authority`, so a mistyped key can't lock the
+/// program out.
+pub(crate) fn set_pending_authority(
+    program_state: &mut Account<ProgramState>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default() && new_authority != program_state.authority,
+        TicketError::InvalidPendingAuthority
+    );
+
+    program_state.pending_authority = Some(new_authority);
+
+    emit!(AuthorityProposedEvent {
+        account: program_state.key(),
+        authority: program_state.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn take_authority(
+    program_state: &mut Account<ProgramState>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require!(
+        program_state.pending_authority == Some(new_authority),
+        TicketError::NoPendingAuthority
+    );
+
+    let previous_authority = program_state.authority;
+    program_state.authority = new_authority;
+    program_state.pending_authority = None;
+
+    emit!(AuthorityTransferredEvent {
+        account: program_state.key(),
+        previous_authority,
+        authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Withdraws a pending handover. Either the current or the proposed
+/// authority may cancel.
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let pending_authority = program_state
+        .pending_authority
+        .ok_or(TicketError::NoPendingAuthority)?;
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == program_state.authority || signer == pending_authority,
+        TicketError::Unauthorized
+    );
+
+    program_state.pending_authority = None;
+
+    emit!(AuthorityTransferCancelledEvent {
+        account: program_state.key(),
+        pending_authority,
+        cancelled_by: signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct UpdateAuthorities<'info> {
+pub struct AcceptAuthority<'info> {
     #[account(
         mut,
-        has_one = authority @ TicketError::Unauthorized,
-        constraint = authority.key() == program_state.authority @ TicketError::Unauthorized
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
     )]
     pub program_state: Account<'info, ProgramState>,
     
-    /// CHECK: Current authority must sign - this is a critical operation
-    pub authority: Signer<'info>,
+    /// The proposed authority, proving it controls the key
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
     
-    /// CHECK: New authority to set (validated in instruction)
-    pub new_authority: UncheckedAccount<'info>,
-}
\ No newline at end of file
+    /// Current or proposed authority
+    pub signer: Signer<'info>,
+}
+
+/// Emitted for both program and event handovers; `account` is the
+/// `ProgramState` or `Event` whose authority is moving.
+#[event]
+pub struct AuthorityProposedEvent {
+    pub account: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub account: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelledEvent {
+    pub account: Pubkey,
+    pub pending_authority: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,71 @@
+use crate::state::program_state::ProgramState;
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+
+/// Changes the platform fee and/or where it is withdrawn to. The new fee
+/// applies to sales from the next transaction on. Only decreases are
+/// immediate; raising the fee goes through `queue_action`.
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    platform_fee_basis_points: Option<u16>,
+    fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    apply_config(
+        &mut ctx.accounts.program_state,
+        ctx.accounts.authority.key(),
+        platform_fee_basis_points,
+        fee_recipient,
+    )
+}
+
+pub(crate) fn apply_config(
+    program_state: &mut Account<ProgramState>,
+    authority: Pubkey,
+    platform_fee_basis_points: Option<u16>,
+    fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    if let Some(fee) = platform_fee_basis_points {
+        require!(
+            fee <= program_state.platform_fee_basis_points,
+            TicketError::TimelockRequired
+        );
+        program_state.platform_fee_basis_points = fee;
+    }
+
+    if let Some(recipient) = fee_recipient {
+        program_state.fee_recipient = recipient;
+    }
+
+    emit!(ConfigUpdatedEvent {
+        authority,
+        fee_recipient: program_state.fee_recipient,
+        platform_fee_basis_points: program_state.platform_fee_basis_points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Authority must sign and match program_state.authority
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub platform_fee_basis_points: u16,
+    pub timestamp: i64,
+}
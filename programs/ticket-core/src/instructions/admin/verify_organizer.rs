@@ -0,0 +1,48 @@
+use crate::state::program_state::ProgramState;
+use crate::state::Organizer;
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+
+/// Sets or clears an organizer's verified badge.
+pub fn set_organizer_verified(ctx: Context<SetOrganizerVerified>, verified: bool) -> Result<()> {
+    let organizer = &mut ctx.accounts.organizer;
+    organizer.verified = verified;
+
+    emit!(OrganizerVerifiedEvent {
+        organizer: organizer.key(),
+        verified,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOrganizerVerified<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.authority.as_ref()],
+        bump = organizer.bump
+    )]
+    pub organizer: Account<'info, Organizer>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct OrganizerVerifiedEvent {
+    pub organizer: Pubkey,
+    pub verified: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
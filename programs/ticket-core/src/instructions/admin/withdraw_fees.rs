@@ -0,0 +1,65 @@
+use crate::state::program_state::ProgramState;
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+
+/// Sends accrued platform fees (everything above rent exemption) to the fee recipient.
+pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+    pay_out_fees(
+        &ctx.accounts.program_state,
+        &ctx.accounts.fee_recipient,
+        ctx.accounts.authority.key(),
+    )
+}
+
+pub(crate) fn pay_out_fees(
+    program_state: &Account<ProgramState>,
+    fee_recipient: &AccountInfo,
+    authority: Pubkey,
+) -> Result<()> {
+    let program_state_info = program_state.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(program_state_info.data_len());
+    let amount = program_state_info.lamports().saturating_sub(rent_minimum);
+
+    require!(amount > 0, TicketError::InsufficientFunds);
+
+    **program_state_info.try_borrow_mut_lamports()? -= amount;
+    **fee_recipient.try_borrow_mut_lamports()? += amount;
+
+    emit!(FeesWithdrawnEvent {
+        authority,
+        fee_recipient: fee_recipient.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ TicketError::Unauthorized,
+        has_one = fee_recipient @ TicketError::Unauthorized,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Must match program_state.fee_recipient
+    #[account(mut)]
+    pub fee_recipient: AccountInfo<'info>,
+
+    /// CHECK: Authority must sign and match program_state.authority
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct FeesWithdrawnEvent {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
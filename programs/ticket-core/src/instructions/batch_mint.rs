@@ -1,59 +1,104 @@
+use crate::utils::{accounts::{collect_payment, create_ticket_account}, math::*};
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::TicketError};
+use crate::{state::{*, program_state::ProgramState}, errors::TicketError, constants::MAX_BATCH_SIZE};
+use crate::traits::{Pausable, PauseScope};
 
-pub fn batch_mint(
-    ctx: Context<BatchMintCtx>,
-    tier: String,
+/// Mints `quantity` tickets in one call. Ticket PDAs for the next sequential
+/// ids are passed as remaining accounts; `recipients` is either empty (all
+/// tickets go to the buyer) or holds one owner per ticket.
+pub fn batch_mint<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchMintCtx<'info>>,
+    tier_index: u8,
     quantity: u32,
+    recipients: Vec<Pubkey>,
 ) -> Result<()> {
-    let event = &mut ctx.accounts.event;
-    
-    // Check if we have enough capacity
-    if event.tickets_sold + quantity > event.total_tickets {
-        return Err(TicketError::InsufficientCapacity.into());
-    }
-    
-    // Max 100 tickets per batch to avoid transaction size limits
-    if quantity > 100 {
+    let tier_index = tier_index as usize;
+
+    ctx.accounts.event.guard(EventAction::Sell)?;
+
+    // Larger batches are split into several transactions by the client
+    require!(quantity > 0, TicketError::InvalidTicketCount);
+    if quantity > MAX_BATCH_SIZE {
         return Err(TicketError::BatchSizeTooLarge.into());
     }
-    
-    // Calculate total price
-    let ticket_price = match tier.as_str() {
-        "general" => event.general_price,
-        "vip" => event.vip_price,
-        _ => return Err(TicketError::InvalidTicketTier.into()),
-    };
-    
+    require!(
+        recipients.is_empty() || recipients.len() == quantity as usize,
+        TicketError::InvalidRecipientCount
+    );
+    require!(
+        ctx.remaining_accounts.len() == quantity as usize,
+        TicketError::InvalidAccount
+    );
+
+    // Seats can only be picked through mint_ticket
+    ctx.accounts.event.require_unseated(tier_index)?;
+
+    // Calculate total price; the whole batch is priced at the current rate
+    let clock = Clock::get()?;
+    let event_key = ctx.accounts.event.key();
+    let ticket_price = ctx
+        .accounts
+        .event
+        .mint_price(event_key, tier_index, clock.unix_timestamp)?;
+
     let total_price = ticket_price
         .checked_mul(quantity as u64)
-        .ok_or(TicketError::MathOverflow)?;
-    
-    // Transfer SOL for all tickets
-    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.buyer.key(),
-        &ctx.accounts.event_authority.key(),
+        .ok_or(TicketError::ArithmeticOverflow)?;
+
+    // Take the platform fee and escrow the rest in the event vault
+    let platform_fee = collect_payment(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
         total_price,
-    );
-    
-    anchor_lang::solana_program::program::invoke(
-        &transfer_instruction,
-        &[
-            ctx.accounts.buyer.to_account_info(),
-            ctx.accounts.event_authority.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
     )?;
-    
-    // Update tickets sold
-    event.tickets_sold += quantity;
-    
-    msg!("Batch minted {} {} tickets for event {}", 
-        quantity, 
-        tier, 
-        event.name
+
+    // Update tier, event and platform counts; the whole batch counts
+    // against the buyer's per-wallet cap, whoever receives the tickets
+    ctx.accounts.event.record_mint(tier_index, quantity)?;
+    ctx.accounts.purchase_record.record_purchase(
+        &ctx.accounts.event,
+        ctx.accounts.buyer.key(),
+        quantity,
+        ctx.bumps.purchase_record,
+    )?;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_tickets_sold = safe_add(program_state.total_tickets_sold, quantity as u64)?;
+
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    for (i, ticket_info) in ctx.remaining_accounts.iter().enumerate() {
+        let owner = recipients.get(i).copied().unwrap_or(buyer.key());
+        let event = &ctx.accounts.event;
+        let ticket = create_ticket_account(event, ticket_info, &buyer, &system_program, |ticket, bump| {
+            ticket.issue(event, owner, tier_index, ticket_price, clock.unix_timestamp, bump);
+            ticket.platform_fee = share_of(platform_fee, quantity as u64, i as u64);
+            ticket.purchaser = buyer.key();
+            Ok(())
+        })?;
+
+        let event = &mut ctx.accounts.event;
+        event.next_ticket_id = safe_add(event.next_ticket_id, 1)?;
+
+        emit!(TicketMintedEvent {
+            event: event.key(),
+            ticket: ticket_info.key(),
+            ticket_id: ticket.ticket_id,
+            owner,
+            tier_index: tier_index as u8,
+            purchase_price: ticket_price,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Batch minted {} {} tickets for event {}",
+        quantity,
+        ctx.accounts.event.tiers[tier_index].name,
+        ctx.accounts.event.name
     );
-    
+
     Ok(())
 }
 
@@ -61,13 +106,36 @@ pub fn batch_mint(
 pub struct BatchMintCtx<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
-    
-    #[account(mut)]
+
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
     pub event: Account<'info, Event>,
-    
-    /// CHECK: We're just transferring SOL to this account
-    #[account(mut)]
-    pub event_authority: AccountInfo<'info>,
-    
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseRecord::LEN,
+        seeds = [b"purchase", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
 }
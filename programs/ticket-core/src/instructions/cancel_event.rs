@@ -1,23 +1,42 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::TicketError;
+use crate::constants::MAX_CANCELLATION_REASON_LEN;
+use super::event_status::set_status;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
 
 pub fn cancel_event(ctx: Context<CancelEvent>, reason: String) -> Result<()> {
-    let event = &mut ctx.accounts.event;
+    cancel(&mut ctx.accounts.event, ctx.accounts.authority.key(), reason)
+}
+
+pub(crate) fn cancel(event: &mut Account<Event>, authority: Pubkey, reason: String) -> Result<()> {
     let clock = Clock::get()?;
     
-    // Validate event can be cancelled
-    require!(!event.cancelled, TicketError::EventAlreadyCancelled);
-    require!(event.start_time > clock.unix_timestamp, TicketError::EventAlreadyStarted);
+    require!(
+        reason.len() <= MAX_CANCELLATION_REASON_LEN,
+        TicketError::CancellationReasonTooLong
+    );
+    // Only events that haven't gone live can be cancelled
+    match event.status {
+        EventStatus::Cancelled => return Err(TicketError::EventAlreadyCancelled.into()),
+        EventStatus::Live | EventStatus::Ended | EventStatus::Settled => {
+            return Err(TicketError::EventAlreadyStarted.into())
+        },
+        _ => {},
+    }
+    set_status(event, EventStatus::Cancelled)?;
     
     // Mark as cancelled
     event.cancelled = true;
     event.cancelled_at = clock.unix_timestamp;
     event.cancellation_reason = reason.clone();
+    // Every paid ticket can now be refunded, whatever the policy
+    event.refund_enabled = true;
     
     emit!(EventCancelledEvent {
-        event_id: event.event_id,
-        authority: ctx.accounts.authority.key(),
+        event_id: event.key(),
+        authority,
         reason,
         timestamp: clock.unix_timestamp,
         refund_enabled: event.refund_enabled,
@@ -30,12 +49,20 @@ pub fn cancel_event(ctx: Context<CancelEvent>, reason: String) -> Result<()> {
 pub struct CancelEvent<'info> {
     #[account(
         mut,
-        has_one = authority @ TicketError::Unauthorized
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
     )]
     pub event: Account<'info, Event>,
     
     /// CHECK: Event authority must sign
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
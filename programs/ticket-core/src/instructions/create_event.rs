@@ -1,14 +1,20 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::TicketError};
+use crate::{state::*, errors::TicketError, constants::MAX_TIERS};
+use crate::utils::validation::*;
+use crate::utils::math::safe_add;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_event(
     ctx: Context<CreateEventCtx>,
     name: String,
     venue: String, 
     event_date: i64,
     total_tickets: u32,
-    general_price: u64,
-    vip_price: u64,
+    tiers: Vec<TicketTierConfig>,
+    payees: Vec<Payee>,
+    max_tickets_per_wallet: Option<u16>,
 ) -> Result<()> {
     msg!("Creating event: {}", name);
     
@@ -28,45 +34,98 @@ pub fn create_event(
         return Err(TicketError::InvalidEventDate.into());
     }
     
-    // Make sure prices make sense (VIP should cost more than GA)
-    if vip_price <= general_price {
-        return Err(TicketError::InvalidPrice.into());
+    // Every event needs at least one tier to sell from
+    require!(!tiers.is_empty(), TicketError::InvalidTier);
+    require!(tiers.len() <= MAX_TIERS, TicketError::TooManyTiers);
+    for tier in &tiers {
+        validate_tier_config(tier)?;
     }
+    let tiers: Vec<TicketTier> = tiers.into_iter().map(TicketTier::from).collect();
+    validate_tier_supply(&tiers, total_tickets)?;
+    validate_payees(&payees)?;
+    validate_wallet_limit(max_tickets_per_wallet)?;
     
+    // Events are numbered per organizer
+    let organizer = &mut ctx.accounts.organizer;
+    let event_id = organizer.event_count;
+    organizer.event_count = safe_add(organizer.event_count, 1)?;
+
     // Save all the event info
     let event = &mut ctx.accounts.event;
+    event.event_id = event_id;
+    event.organizer_account = organizer.key();
     event.authority = ctx.accounts.authority.key();
+    event.organizer = ctx.accounts.authority.key();
+    event.treasury = organizer.default_treasury;
+    event.refund_policy = organizer.default_refund_policy.clone();
     event.name = name;
     event.venue = venue;
     event.event_date = event_date;
+    event.start_time = event_date;
     event.total_tickets = total_tickets;
     event.tickets_sold = 0;  // None sold yet
-    event.general_price = general_price;
-    event.vip_price = vip_price;
+    event.tiers = tiers;
+    event.payees = payees;
+    event.max_tickets_per_wallet = max_tickets_per_wallet;
+    event.price_multiplier = 100; // 1x until surge pricing kicks in
+    event.status = EventStatus::Draft; // Sales open with open_sales
     event.bump = ctx.bumps.event;
     
+    // Revenue escrow for this event
+    let vault = &mut ctx.accounts.vault;
+    vault.event = event.key();
+    vault.bump = ctx.bumps.vault;
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_events = safe_add(program_state.total_events, 1)?;
+    
     msg!("Event created successfully!");
-    msg!("GA Price: {} lamports", general_price);
-    msg!("VIP Price: {} lamports", vip_price);
+    for tier in &event.tiers {
+        msg!("Tier {}: {} lamports x {}", tier.name, tier.price, tier.total_supply);
+    }
     msg!("Total Tickets: {}", total_tickets);
     
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(name: String)]
 pub struct CreateEventCtx<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        mut,
+        seeds = [b"organizer", authority.key().as_ref()],
+        bump = organizer.bump,
+        has_one = authority @ TicketError::Unauthorized
+    )]
+    pub organizer: Account<'info, Organizer>,
+    
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 50 + 50 + 8 + 4 + 4 + 8 + 8 + 1,
-        seeds = [b"event", name.as_bytes()],
+        space = Event::LEN,
+        seeds = [b"event", organizer.key().as_ref(), organizer.event_count.to_le_bytes().as_ref()],
         bump
     )]
     pub event: Account<'info, Event>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = EventVault::LEN,
+        seeds = [b"vault", event.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, EventVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::state::program_state::ProgramState;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+use super::admin::{AuthorityProposedEvent, AuthorityTransferredEvent, AuthorityTransferCancelledEvent};
+
+/// Hands an event to a new organizer in two steps, like the program authority
+/// handover but without its timelock. On acceptance both `authority` and
+/// `organizer` move to the new key; the treasury stays where it is until the
+/// new owner calls `set_event_treasury`. `organizer_account` stays on the original Organizer because it
+/// seeds the event address, so it says nothing about the current owner.
+pub fn propose_event_authority(ctx: Context<ProposeEventAuthority>, new_authority: Pubkey) -> Result<()> {
+    set_pending_event_authority(&mut ctx.accounts.event, new_authority)
+}
+
+pub fn accept_event_authority(ctx: Context<AcceptEventAuthority>) -> Result<()> {
+    take_event_authority(&mut ctx.accounts.event, ctx.accounts.new_authority.key())
+}
+
+pub(crate) fn set_pending_event_authority(event: &mut Account<Event>, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default() && new_authority != event.authority,
+        TicketError::InvalidPendingAuthority
+    );
+
+    event.pending_authority = Some(new_authority);
+    event.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AuthorityProposedEvent {
+        account: event.key(),
+        authority: event.authority,
+        pending_authority: new_authority,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn take_event_authority(event: &mut Account<Event>, new_authority: Pubkey) -> Result<()> {
+    require!(
+        event.pending_authority == Some(new_authority),
+        TicketError::NoPendingAuthority
+    );
+
+    let previous_authority = event.authority;
+    event.authority = new_authority;
+    event.organizer = new_authority;
+    event.pending_authority = None;
+    event.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AuthorityTransferredEvent {
+        account: event.key(),
+        previous_authority,
+        authority: new_authority,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_event_authority_transfer(ctx: Context<CancelEventAuthorityTransfer>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let pending_authority = event
+        .pending_authority
+        .ok_or(TicketError::NoPendingAuthority)?;
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == event.authority || signer == pending_authority,
+        TicketError::Unauthorized
+    );
+
+    event.pending_authority = None;
+    event.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AuthorityTransferCancelledEvent {
+        account: event.key(),
+        pending_authority,
+        cancelled_by: signer,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeEventAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptEventAuthority<'info> {
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The proposed authority, proving it controls the key
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct CancelEventAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    /// Current or proposed authority
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventStatus, EventStatusChangedEvent};
+use crate::errors::TicketError;
+use crate::constants::ENTRY_GRACE_SECONDS;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Draft -> OnSale.
+pub fn open_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+    set_status(&mut ctx.accounts.event, EventStatus::OnSale)
+}
+
+/// OnSale -> SalesPaused. Tickets already sold stay valid.
+pub fn pause_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+    set_status(&mut ctx.accounts.event, EventStatus::SalesPaused)
+}
+
+/// SalesPaused -> OnSale.
+pub fn resume_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+    set_status(&mut ctx.accounts.event, EventStatus::OnSale)
+}
+
+/// OnSale / SalesPaused -> Live once the start time has passed. Anyone can
+/// crank this so the gates don't depend on the organizer being online.
+pub fn start_event(ctx: Context<AdvanceEventStatus>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    require!(
+        Clock::get()?.unix_timestamp >= event.start_time,
+        TicketError::EventNotStarted
+    );
+    set_status(event, EventStatus::Live)
+}
+
+/// Live -> Ended once the entry grace period after the event is over.
+pub fn end_event(ctx: Context<AdvanceEventStatus>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    require!(
+        Clock::get()?.unix_timestamp >= event.ends_at() + ENTRY_GRACE_SECONDS,
+        TicketError::EventNotEnded
+    );
+    set_status(event, EventStatus::Ended)
+}
+
+pub(crate) fn set_status(event: &mut Account<Event>, status: EventStatus) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let previous = event.transition(status, now)?;
+
+    emit!(EventStatusChangedEvent {
+        event: event.key(),
+        previous,
+        status,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetEventStatus<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct AdvanceEventStatus<'info> {
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
@@ -0,0 +1,279 @@
+use crate::utils::{accounts::{collect_payment, create_ticket_account}, math::*};
+use anchor_lang::prelude::*;
+use crate::{state::{*, program_state::ProgramState}, errors::TicketError};
+use crate::constants::{HOLD_DURATION_SECONDS, MAX_BATCH_SIZE};
+use crate::traits::{Pausable, PauseScope};
+
+/// Locks `quantity` tickets of a tier for the buyer at the current price.
+/// The hold lasts HOLD_DURATION_SECONDS; a buyer has at most one per event.
+pub fn hold_tickets(ctx: Context<HoldTickets>, tier_index: u8, quantity: u32) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.event.guard(EventAction::Sell)?;
+    require!(quantity > 0, TicketError::InvalidTicketCount);
+    if quantity > MAX_BATCH_SIZE {
+        return Err(TicketError::BatchSizeTooLarge.into());
+    }
+    ctx.accounts.purchase_record.check_hold(
+        &ctx.accounts.event,
+        ctx.accounts.buyer.key(),
+        quantity,
+        ctx.bumps.purchase_record,
+    )?;
+
+    let event = &mut ctx.accounts.event;
+    let hold = &mut ctx.accounts.hold;
+
+    event.require_unseated(tier_index as usize)?;
+    let event_key = event.key();
+    let price = event.mint_price(event_key, tier_index as usize, clock.unix_timestamp)?;
+    event.hold_tickets(tier_index as usize, quantity)?;
+
+    hold.event = event_key;
+    hold.buyer = ctx.accounts.buyer.key();
+    hold.tier_index = tier_index;
+    hold.quantity = quantity;
+    hold.price = price;
+    hold.created_at = clock.unix_timestamp;
+    hold.expires_at = clock
+        .unix_timestamp
+        .checked_add(HOLD_DURATION_SECONDS)
+        .ok_or(TicketError::ArithmeticOverflow)?;
+    hold.bump = ctx.bumps.hold;
+
+    emit!(TicketsHeldEvent {
+        event: event_key,
+        buyer: hold.buyer,
+        tier_index,
+        quantity,
+        price,
+        expires_at: hold.expires_at,
+    });
+
+    Ok(())
+}
+
+/// Pays for a live hold and issues its tickets to the buyer. Ticket PDAs for
+/// the next sequential ids are passed as remaining accounts, as in `batch_mint`.
+pub fn purchase_hold<'info>(ctx: Context<'_, '_, 'info, 'info, PurchaseHold<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let hold = &ctx.accounts.hold;
+    let tier_index = hold.tier_index as usize;
+    let quantity = hold.quantity;
+    let ticket_price = hold.price;
+
+    ctx.accounts.event.guard(EventAction::Sell)?;
+    require!(clock.unix_timestamp < hold.expires_at, TicketError::ReservationExpired);
+    require!(
+        ctx.remaining_accounts.len() == quantity as usize,
+        TicketError::InvalidAccount
+    );
+
+    let total_price = ticket_price
+        .checked_mul(quantity as u64)
+        .ok_or(TicketError::ArithmeticOverflow)?;
+
+    // Take the platform fee and escrow the rest in the event vault
+    let platform_fee = collect_payment(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        total_price,
+    )?;
+
+    // Held tickets become sold ones
+    ctx.accounts.event.release_hold(tier_index, quantity);
+    ctx.accounts.event.record_mint(tier_index, quantity)?;
+    ctx.accounts.purchase_record.record_purchase(
+        &ctx.accounts.event,
+        ctx.accounts.buyer.key(),
+        quantity,
+        ctx.bumps.purchase_record,
+    )?;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_tickets_sold = safe_add(program_state.total_tickets_sold, quantity as u64)?;
+
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    for (i, ticket_info) in ctx.remaining_accounts.iter().enumerate() {
+        let event = &ctx.accounts.event;
+        let ticket = create_ticket_account(event, ticket_info, &buyer, &system_program, |ticket, bump| {
+            ticket.issue(event, buyer.key(), tier_index, ticket_price, clock.unix_timestamp, bump);
+            ticket.platform_fee = share_of(platform_fee, quantity as u64, i as u64);
+            Ok(())
+        })?;
+
+        let event = &mut ctx.accounts.event;
+        event.next_ticket_id = safe_add(event.next_ticket_id, 1)?;
+
+        emit!(TicketMintedEvent {
+            event: event.key(),
+            ticket: ticket_info.key(),
+            ticket_id: ticket.ticket_id,
+            owner: buyer.key(),
+            tier_index: tier_index as u8,
+            purchase_price: ticket_price,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Purchased hold of {} {} tickets for event {}",
+        quantity,
+        ctx.accounts.event.tiers[tier_index].name,
+        ctx.accounts.event.name
+    );
+
+    Ok(())
+}
+
+/// Permissionless crank returning expired holds to inventory. Remaining
+/// accounts are `(hold, buyer)` pairs; each hold is closed and its rent goes
+/// back to the buyer.
+pub fn release_expired_holds<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleaseExpiredHolds<'info>>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        TicketError::InvalidAccount
+    );
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (hold_info, buyer_info) = (&pair[0], &pair[1]);
+        let hold = Account::<TicketHold>::try_from(hold_info)?;
+
+        require_keys_eq!(hold.event, event.key(), TicketError::InvalidAccount);
+        require_keys_eq!(buyer_info.key(), hold.buyer, TicketError::InvalidAccount);
+        require!(clock.unix_timestamp >= hold.expires_at, TicketError::HoldNotExpired);
+
+        event.release_hold(hold.tier_index as usize, hold.quantity);
+
+        emit!(HoldReleasedEvent {
+            event: hold.event,
+            buyer: hold.buyer,
+            tier_index: hold.tier_index,
+            quantity: hold.quantity,
+            timestamp: clock.unix_timestamp,
+        });
+
+        hold.close(buyer_info.clone())?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HoldTickets<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = TicketHold::LEN,
+        seeds = [b"hold", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub hold: Account<'info, TicketHold>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseRecord::LEN,
+        seeds = [b"purchase", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseHold<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"hold", event.key().as_ref(), buyer.key().as_ref()],
+        bump = hold.bump,
+        constraint = hold.quantity > 0 @ TicketError::NoActiveReservation
+    )]
+    pub hold: Account<'info, TicketHold>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseRecord::LEN,
+        seeds = [b"purchase", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// No pause check: expired holds must go back to inventory even during a pause
+#[derive(Accounts)]
+pub struct ReleaseExpiredHolds<'info> {
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct TicketsHeldEvent {
+    pub event: Pubkey,
+    pub buyer: Pubkey,
+    pub tier_index: u8,
+    pub quantity: u32,
+    pub price: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct HoldReleasedEvent {
+    pub event: Pubkey,
+    pub buyer: Pubkey,
+    pub tier_index: u8,
+    pub quantity: u32,
+    pub timestamp: i64,
+}
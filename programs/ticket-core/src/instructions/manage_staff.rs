@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, EventRole, RoleConfig};
+use crate::errors::TicketError;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Gives `wallet` a staff role on the event, replacing any role it already
+/// holds there.
+pub fn grant_role(ctx: Context<GrantRole>, wallet: Pubkey, config: RoleConfig) -> Result<()> {
+    assign_role(
+        &ctx.accounts.event,
+        &mut ctx.accounts.role,
+        wallet,
+        config,
+        ctx.accounts.authority.key(),
+        ctx.bumps.role,
+    )
+}
+
+pub(crate) fn assign_role(
+    event: &Account<Event>,
+    role: &mut EventRole,
+    wallet: Pubkey,
+    config: RoleConfig,
+    granted_by: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    event.guard(EventAction::Staff)?;
+    require!(wallet != Pubkey::default(), TicketError::InvalidAccount);
+    config.validate(now)?;
+
+    role.event = event.key();
+    role.wallet = wallet;
+    role.permissions = config.permissions;
+    role.gate = config.gate;
+    role.tiers = config.tiers;
+    role.expires_at = config.expires_at;
+    role.granted_by = granted_by;
+    role.granted_at = now;
+    role.bump = bump;
+
+    emit!(RoleGrantedEvent {
+        event: role.event,
+        wallet,
+        permissions: role.permissions,
+        gate: role.gate.clone(),
+        tiers: role.tiers,
+        expires_at: role.expires_at,
+        granted_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Closes the wallet's role. Allowed in any status so rights can always be pulled.
+pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    emit_revoked(&ctx.accounts.role, ctx.accounts.authority.key())
+}
+
+pub(crate) fn emit_revoked(role: &EventRole, revoked_by: Pubkey) -> Result<()> {
+    emit!(RoleRevokedEvent {
+        event: role.event,
+        wallet: role.wallet,
+        permissions: role.permissions,
+        revoked_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EventRole::LEN,
+        seeds = [b"role", event.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub role: Account<'info, EventRole>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// No pause check: revoking a compromised staff wallet must always be possible
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(has_one = authority @ TicketError::Unauthorized)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", event.key().as_ref(), role.wallet.as_ref()],
+        bump = role.bump
+    )]
+    pub role: Account<'info, EventRole>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// With `RoleRevokedEvent`, gives the full history of who held which rights when.
+#[event]
+pub struct RoleGrantedEvent {
+    pub event: Pubkey,
+    pub wallet: Pubkey,
+    pub permissions: u16,
+    pub gate: Option<String>,
+    pub tiers: Option<u16>,
+    pub expires_at: Option<i64>,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevokedEvent {
+    pub event: Pubkey,
+    pub wallet: Pubkey,
+    pub permissions: u16,                   // What the wallet held until now
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
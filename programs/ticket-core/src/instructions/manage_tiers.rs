@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, TicketTier, TicketTierConfig, TierStatus, TierUpdate};
+use crate::errors::TicketError;
+use crate::constants::MAX_TIERS;
+use crate::utils::validation::*;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+pub fn add_tier(ctx: Context<AddTier>, config: TicketTierConfig) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+
+    event.guard(EventAction::Configure)?;
+    require!(event.tiers.len() < MAX_TIERS, TicketError::TooManyTiers);
+    validate_tier_config(&config)?;
+
+    event.tiers.push(TicketTier::from(config));
+    validate_tier_supply(&event.tiers, event.total_tickets)?;
+    event.updated_at = clock.unix_timestamp;
+
+    let tier_index = event.tiers.len() - 1;
+    emit_tier_updated(event, tier_index, clock.unix_timestamp);
+
+    msg!("Tier {} added to event {}", event.tiers[tier_index].name, event.name);
+
+    Ok(())
+}
+
+pub fn update_tier(ctx: Context<UpdateTier>, tier_index: u8, update: TierUpdate) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+    let tier_index = tier_index as usize;
+
+    event.guard(EventAction::Configure)?;
+
+    let tier = event
+        .tiers
+        .get_mut(tier_index)
+        .ok_or(TicketError::InvalidTier)?;
+    require!(tier.status != TierStatus::Closed, TicketError::TierClosed);
+
+    if let Some(new_price) = update.price {
+        require!(new_price > 0, TicketError::InvalidTicketPrice);
+        tier.price = new_price;
+    }
+
+    if let Some(new_supply) = update.total_supply {
+        let committed = tier
+            .minted_count
+            .checked_add(tier.held_count)
+            .ok_or(TicketError::ArithmeticOverflow)?;
+        require!(new_supply >= committed, TicketError::TierSupplyBelowMinted);
+        tier.total_supply = new_supply;
+    }
+
+    if let Some(enabled) = update.dynamic_pricing_enabled {
+        tier.dynamic_pricing_enabled = enabled;
+    }
+    if update.min_price.is_some() {
+        tier.min_price = update.min_price;
+    }
+    if update.max_price.is_some() {
+        tier.max_price = update.max_price;
+    }
+    if let (Some(min), Some(max)) = (tier.min_price, tier.max_price) {
+        require!(min <= max, TicketError::InvalidTierPriceBounds);
+    }
+
+    if let Some(curve) = update.pricing_curve {
+        validate_pricing_curve(&curve)?;
+        tier.pricing_curve = curve;
+    }
+
+    if let Some(paused) = update.paused {
+        tier.status = if paused { TierStatus::Paused } else { TierStatus::Active };
+    }
+
+    validate_tier_supply(&event.tiers, event.total_tickets)?;
+    event.updated_at = clock.unix_timestamp;
+
+    emit_tier_updated(event, tier_index, clock.unix_timestamp);
+
+    Ok(())
+}
+
+/// Retires a tier permanently. Unsold supply is released back to the event.
+pub fn close_tier(ctx: Context<CloseTier>, tier_index: u8) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+    let tier_index = tier_index as usize;
+
+    event.guard(EventAction::Configure)?;
+    let tier = event
+        .tiers
+        .get_mut(tier_index)
+        .ok_or(TicketError::InvalidTier)?;
+    require!(tier.status != TierStatus::Closed, TicketError::TierClosed);
+
+    tier.status = TierStatus::Closed;
+    tier.total_supply = tier.minted_count;
+    event.updated_at = clock.unix_timestamp;
+
+    emit_tier_updated(event, tier_index, clock.unix_timestamp);
+
+    msg!("Tier {} closed", event.tiers[tier_index].name);
+
+    Ok(())
+}
+
+fn emit_tier_updated(event: &Account<Event>, tier_index: usize, timestamp: i64) {
+    let tier = &event.tiers[tier_index];
+    emit!(TierUpdatedEvent {
+        event: event.key(),
+        tier_index: tier_index as u8,
+        name: tier.name.clone(),
+        price: tier.price,
+        total_supply: tier.total_supply,
+        minted_count: tier.minted_count,
+        status: tier.status,
+        timestamp,
+    });
+}
+
+#[derive(Accounts)]
+pub struct AddTier<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTier<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTier<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct TierUpdatedEvent {
+    pub event: Pubkey,
+    pub tier_index: u8,
+    pub name: String,
+    pub price: u64,
+    pub total_supply: u32,
+    pub minted_count: u32,
+    pub status: TierStatus,
+    pub timestamp: i64,
+}
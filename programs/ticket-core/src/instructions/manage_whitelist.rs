@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, Whitelist};
+use crate::errors::TicketError;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+pub fn create_whitelist(
+    ctx: Context<CreateWhitelist>,
+    merkle_root: [u8; 32],
+    max_per_wallet: u8,
+    discount_basis_points: u16,
+) -> Result<()> {
+    ctx.accounts.event.guard(EventAction::Configure)?;
+    require!(max_per_wallet > 0, TicketError::InvalidTicketCount);
+    require!(discount_basis_points <= 10_000, TicketError::InvalidDiscount);
+
+    let whitelist = &mut ctx.accounts.whitelist;
+    whitelist.event = ctx.accounts.event.key();
+    whitelist.merkle_root = merkle_root;
+    whitelist.max_per_wallet = max_per_wallet;
+    whitelist.active = true;
+    whitelist.discount_basis_points = discount_basis_points;
+    whitelist.total_minted = 0;
+    whitelist.bump = ctx.bumps.whitelist;
+
+    emit_whitelist_updated(whitelist)
+}
+
+/// Swaps in a new list. Wallets keep the counts they already minted.
+pub fn rotate_whitelist_root(ctx: Context<ManageWhitelist>, merkle_root: [u8; 32]) -> Result<()> {
+    ctx.accounts.event.guard(EventAction::Configure)?;
+    let whitelist = &mut ctx.accounts.whitelist;
+    require!(whitelist.active, TicketError::WhitelistInactive);
+
+    whitelist.merkle_root = merkle_root;
+
+    emit_whitelist_updated(whitelist)
+}
+
+/// Ends the presale. Deactivation is permanent.
+pub fn deactivate_whitelist(ctx: Context<ManageWhitelist>) -> Result<()> {
+    let whitelist = &mut ctx.accounts.whitelist;
+    require!(whitelist.active, TicketError::WhitelistInactive);
+
+    whitelist.active = false;
+
+    emit_whitelist_updated(whitelist)
+}
+
+fn emit_whitelist_updated(whitelist: &Account<Whitelist>) -> Result<()> {
+    emit!(WhitelistUpdatedEvent {
+        event: whitelist.event,
+        whitelist: whitelist.key(),
+        merkle_root: whitelist.merkle_root,
+        max_per_wallet: whitelist.max_per_wallet,
+        discount_basis_points: whitelist.discount_basis_points,
+        active: whitelist.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateWhitelist<'info> {
+    #[account(
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Whitelist::LEN,
+        seeds = [b"whitelist", event.key().as_ref()],
+        bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageWhitelist<'info> {
+    #[account(
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"whitelist", event.key().as_ref()],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct WhitelistUpdatedEvent {
+    pub event: Pubkey,
+    pub whitelist: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_per_wallet: u8,
+    pub discount_basis_points: u16,
+    pub active: bool,
+    pub timestamp: i64,
+}
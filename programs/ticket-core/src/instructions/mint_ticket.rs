@@ -1,53 +1,98 @@
-use crate::utils::math::*;
+use crate::utils::{accounts::{claim_seat, collect_payment}, math::*};
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::TicketError};
+use crate::{state::{*, program_state::ProgramState}, errors::TicketError};
+use crate::traits::{Pausable, PauseScope};
 
+/// Mints one ticket. Seated tiers require a `seat`, which is claimed
+/// atomically together with the `seating_map` and `seat` PDA accounts.
 pub fn mint_ticket(
     ctx: Context<MintTicketCtx>,
-    tier: String, // "general" or "vip"
+    tier_index: u8,
+    seat: Option<SeatAssignment>,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
-    
-    // Check if event has capacity
-    if event.tickets_sold >= event.total_tickets {
-        return Err(TicketError::EventSoldOut.into());
-    }
-    
-    // Determine price based on tier
-    let ticket_price = match tier.as_str() {
-        "general" => event.general_price,
-        "vip" => event.vip_price,
-        _ => return Err(TicketError::InvalidTicketTier.into()),
-    };
-    
-    // Transfer SOL from buyer to event authority
-    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.buyer.key(),
-        &ctx.accounts.event_authority.key(),
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+    let tier_index = tier_index as usize;
+
+    event.guard(EventAction::Sell)?;
+
+    // Resolve the tier and its current (possibly surge) price
+    let event_key = event.key();
+    let ticket_price = event.mint_price(event_key, tier_index, clock.unix_timestamp)?;
+
+    // Take the platform fee and escrow the rest in the event vault
+    let platform_fee = collect_payment(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
         ticket_price,
-    );
-    
-    anchor_lang::solana_program::program::invoke(
-        &transfer_instruction,
-        &[
-            ctx.accounts.buyer.to_account_info(),
-            ctx.accounts.event_authority.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
     )?;
-    
-    // Increment tickets sold
-    event.tickets_sold = safe_add(event.tickets_sold as u64, 1)? as u32;
-    
-
-    // For now, we're just tracking the sale
-    
-    msg!("Ticket minted! Event: {}, Tier: {}, Ticket #: {}", 
-        event.name, 
-        tier, 
-        event.tickets_sold
+
+    // Enforce tier and event supply, then the buyer's per-wallet cap
+    event.record_mint(tier_index, 1)?;
+    ctx.accounts.purchase_record.record_purchase(
+        event,
+        ctx.accounts.buyer.key(),
+        1,
+        ctx.bumps.purchase_record,
+    )?;
+
+    // Issue the ticket to the buyer
+    ticket.issue(
+        event,
+        ctx.accounts.buyer.key(),
+        tier_index,
+        ticket_price,
+        clock.unix_timestamp,
+        ctx.bumps.ticket,
     );
-    
+    ticket.platform_fee = platform_fee;
+
+    // Reserved seating: claim the seat PDA, which fails if it is already taken
+    match seat {
+        Some(seat) => {
+            let seating_map = ctx
+                .accounts
+                .seating_map
+                .as_ref()
+                .ok_or(TicketError::InvalidAccount)?;
+            seating_map.validate_seat(&seat, tier_index)?;
+            let seat_info = ctx.accounts.seat.as_ref().ok_or(TicketError::InvalidAccount)?;
+            claim_seat(
+                event,
+                seat_info,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                seat,
+                ticket.key(),
+            )?;
+            ticket.seat = Some(seat);
+        },
+        None => event.require_unseated(tier_index)?,
+    }
+    event.next_ticket_id = safe_add(event.next_ticket_id, 1)?;
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_tickets_sold = safe_add(program_state.total_tickets_sold, 1)?;
+
+    emit!(TicketMintedEvent {
+        event: event.key(),
+        ticket: ticket.key(),
+        ticket_id: ticket.ticket_id,
+        owner: ticket.owner,
+        tier_index: tier_index as u8,
+        purchase_price: ticket_price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket minted! Event: {}, Tier: {}, Ticket #: {}",
+        event.name,
+        event.tiers[tier_index].name,
+        ticket.ticket_id
+    );
+
     Ok(())
 }
 
@@ -55,13 +100,56 @@ pub fn mint_ticket(
 pub struct MintTicketCtx<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
-    
-    #[account(mut)]
+
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
     pub event: Account<'info, Event>,
-    
-    /// CHECK: We're just transferring SOL to this account
+
+    #[account(
+        init,
+        payer = buyer,
+        space = Ticket::LEN,
+        seeds = [b"ticket", event.key().as_ref(), event.next_ticket_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseRecord::LEN,
+        seeds = [b"purchase", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// Required when minting a seat
+    #[account(
+        seeds = [b"seating_map", event.key().as_ref()],
+        bump = seating_map.bump
+    )]
+    pub seating_map: Option<Account<'info, SeatingMap>>,
+
+    /// CHECK: Seat PDA, created by the handler; must not exist yet
     #[account(mut)]
-    pub event_authority: AccountInfo<'info>,
-    
+    pub seat: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
 }
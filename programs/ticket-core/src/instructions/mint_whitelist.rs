@@ -1,72 +1,163 @@
-use crate::utils::math::*;
-use anchor_lang::prelude::*;
-use crate::{state::*, errors::TicketError};
-
-pub fn mint_whitelist(
-    ctx: Context<MintWhitelistCtx>,
-    tier: String,
-    _proof: Vec<[u8; 32]>, // Merkle proof for whitelist
-) -> Result<()> {
-    let event = &mut ctx.accounts.event;
-    
-    // Check if event has capacity
-    if event.tickets_sold >= event.total_tickets {
-        return Err(TicketError::EventSoldOut.into());
-    }
-    
+use crate::utils::{accounts::collect_payment, math::*, security::*};
+use anchor_lang::prelude::*;
+use crate::{state::{*, program_state::ProgramState}, errors::TicketError};
+use crate::traits::{Pausable, PauseScope};
 
-    // For now, we'll just check if whitelist is active
-    let clock = Clock::get()?;
-    if clock.unix_timestamp > event.event_date - 86400 { // 24 hours before event
-        return Err(TicketError::WhitelistExpired.into());
-    }
-    
-    // Whitelist gets 20% discount
-    let ticket_price = match tier.as_str() {
-        "general" => event.general_price * 80 / 100, // 20% off
-        "vip" => event.vip_price * 80 / 100,         // 20% off
-        _ => return Err(TicketError::InvalidTicketTier.into()),
-    };
-    
-    // Transfer SOL from buyer to event authority
-    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.buyer.key(),
-        &ctx.accounts.event_authority.key(),
-        ticket_price,
-    );
-    
-    anchor_lang::solana_program::program::invoke(
-        &transfer_instruction,
-        &[
-            ctx.accounts.buyer.to_account_info(),
-            ctx.accounts.event_authority.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-    )?;
-    
-    // Increment tickets sold
-    event.tickets_sold = safe_add(event.tickets_sold as u64, 1)? as u32;
-    
-    msg!("Whitelist ticket minted! Event: {}, Tier: {}, Discounted Price: {}", 
-        event.name, 
-        tier, 
-        ticket_price
-    );
-    
-    Ok(())
-}
-
-#[derive(Accounts)]
-pub struct MintWhitelistCtx<'info> {
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    
-    #[account(mut)]
-    pub event: Account<'info, Event>,
-    
-    /// CHECK: We're just transferring SOL to this account
-    #[account(mut)]
-    pub event_authority: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
+/// Presale mint for whitelisted wallets. `allocation` must match the leaf the
+/// wallet was listed with; leaves without one fall back to `max_per_wallet`.
+pub fn mint_whitelist(
+    ctx: Context<MintWhitelistCtx>,
+    tier_index: u8,
+    allocation: Option<u8>,
+    proof: Vec<[u8; 32]>, // Merkle proof for whitelist
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let whitelist = &mut ctx.accounts.whitelist;
+    let claim = &mut ctx.accounts.claim;
+    let ticket = &mut ctx.accounts.ticket;
+    let buyer = ctx.accounts.buyer.key();
+    let tier_index = tier_index as usize;
+    
+    event.guard(EventAction::Sell)?;
+    require!(whitelist.active, TicketError::WhitelistInactive);
+    
+    // Presale closes 24 hours before the event
+    let clock = Clock::get()?;
+    if clock.unix_timestamp > event.start_time - 86400 {
+        return Err(TicketError::WhitelistExpired.into());
+    }
+    
+    let leaf = whitelist_leaf(&buyer, allocation);
+    require!(
+        verify_merkle_proof(&proof, &whitelist.merkle_root, leaf),
+        TicketError::InvalidMerkleProof
+    );
+    
+    // First mint against this whitelist creates the wallet's claim record
+    if claim.wallet == Pubkey::default() {
+        claim.whitelist = whitelist.key();
+        claim.wallet = buyer;
+        claim.bump = ctx.bumps.claim;
+    }
+    let limit = allocation.unwrap_or(whitelist.max_per_wallet);
+    require!(claim.minted < limit, TicketError::WhitelistAllocationExceeded);
+    claim.minted += 1;
+    whitelist.total_minted = whitelist
+        .total_minted
+        .checked_add(1)
+        .ok_or(TicketError::ArithmeticOverflow)?;
+    
+    event.require_unseated(tier_index)?;
+    let event_key = event.key();
+    let tier_price = event.mint_price(event_key, tier_index, clock.unix_timestamp)?;
+    let discount = calculate_percentage(tier_price, whitelist.discount_basis_points)?;
+    let ticket_price = safe_sub(tier_price, discount)?;
+    
+    // Take the platform fee and escrow the rest in the event vault
+    let platform_fee = collect_payment(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ticket_price,
+    )?;
+    
+    // Enforce tier and event supply, then the buyer's per-wallet cap
+    event.record_mint(tier_index, 1)?;
+    ctx.accounts.purchase_record.record_purchase(event, buyer, 1, ctx.bumps.purchase_record)?;
+    
+    ticket.issue(
+        event,
+        buyer,
+        tier_index,
+        ticket_price,
+        clock.unix_timestamp,
+        ctx.bumps.ticket,
+    );
+    ticket.platform_fee = platform_fee;
+    event.next_ticket_id = safe_add(event.next_ticket_id, 1)?;
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_tickets_sold = safe_add(program_state.total_tickets_sold, 1)?;
+    
+    emit!(TicketMintedEvent {
+        event: event.key(),
+        ticket: ticket.key(),
+        ticket_id: ticket.ticket_id,
+        owner: ticket.owner,
+        tier_index: tier_index as u8,
+        purchase_price: ticket_price,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Whitelist ticket minted! Event: {}, Tier: {}, Discounted Price: {}",
+        event.name,
+        event.tiers[tier_index].name,
+        ticket_price
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintWhitelistCtx<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"whitelist", event.key().as_ref()],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = WhitelistClaim::LEN,
+        seeds = [b"whitelist_claim", whitelist.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, WhitelistClaim>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        space = Ticket::LEN,
+        seeds = [b"ticket", event.key().as_ref(), event.next_ticket_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PurchaseRecord::LEN,
+        seeds = [b"purchase", event.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -1,18 +1,44 @@
+pub mod organizer;
 pub mod create_event;
 pub mod update_event;
+pub mod event_status;
+pub mod pause_event;
+pub mod event_authority;
+pub mod multisig;
 pub mod cancel_event;
+pub mod settle_event;
+pub mod revenue_splits;
 pub mod mint_ticket;
 pub mod batch_mint;
 pub mod reserve_tickets;
 pub mod mint_whitelist;
+pub mod holds;
+pub mod manage_whitelist;
+pub mod manage_tiers;
+pub mod manage_staff;
+pub mod seating;
+pub mod series;
 
+pub use organizer::*;
 pub use create_event::*;
 pub use update_event::*;
+pub use event_status::*;
+pub use pause_event::*;
+pub use event_authority::*;
+pub use multisig::*;
 pub use cancel_event::*;
+pub use settle_event::*;
+pub use revenue_splits::*;
 pub use mint_ticket::*;
 pub use batch_mint::*;
 pub use reserve_tickets::*;
 pub use mint_whitelist::*;
+pub use holds::*;
+pub use manage_whitelist::*;
+pub use manage_tiers::*;
+pub use manage_staff::*;
+pub use seating::*;
+pub use series::*;
 // Days 6-7: Transfer & Validation
 pub mod transfer_ticket;
 pub mod delegate_transfer;
@@ -0,0 +1,487 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventRole, EventStatus, EventVault, Multisig, MultisigAction, MultisigProposal, QueuedAction, Ticket};
+use crate::state::program_state::ProgramState;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+use crate::utils::{accounts::{close_account, create_queued_action_account, init_role_account}, security::verify_multisig};
+use super::admin::{
+    apply_config, emit_cancelled, pause_program, pay_out_fees, queue, take_authority,
+    unpause_program,
+};
+use super::cancel_event::cancel;
+use super::event_authority::{set_pending_event_authority, take_event_authority};
+use super::event_status::set_status;
+use super::manage_staff::{assign_role, emit_revoked};
+use super::pause_event::{pause_event_scopes, unpause_event_scopes};
+use super::refunds::{pay_refund, RefundRequest};
+use super::settle_event::settle;
+use super::update_event::change_treasury;
+
+/// Creates a signer set. Point an authority at it with a queued
+/// `ProposeAuthority` (or `propose_event_authority`) and an `AcceptAuthority`
+/// proposal.
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    multisig_id: u64,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate(&members, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.creator = ctx.accounts.creator.key();
+    multisig.multisig_id = multisig_id;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.created_at = Clock::get()?.unix_timestamp;
+    multisig.bump = ctx.bumps.multisig;
+
+    emit!(MultisigCreatedEvent {
+        multisig: multisig.key(),
+        creator: multisig.creator,
+        members: multisig.members.clone(),
+        threshold,
+        timestamp: multisig.created_at,
+    });
+
+    Ok(())
+}
+
+/// Opens a proposal; the proposer's approval is recorded with it.
+pub fn create_proposal(ctx: Context<CreateProposal>, action: MultisigAction) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = ctx.accounts.proposer.key();
+
+    action.validate(Clock::get()?.unix_timestamp)?;
+
+    proposal.multisig = multisig.key();
+    proposal.proposal_id = multisig.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed_at = None;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig
+        .proposal_count
+        .checked_add(1)
+        .ok_or(TicketError::ArithmeticOverflow)?;
+
+    emit!(ProposalCreatedEvent {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        proposer,
+        action: proposal.action.clone(),
+        timestamp: proposal.created_at,
+    });
+
+    Ok(())
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+
+    proposal.approve(member)?;
+
+    emit!(ProposalApprovedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        member,
+        approvals: proposal.approvals.len() as u8,
+        threshold: ctx.accounts.multisig.threshold,
+    });
+
+    Ok(())
+}
+
+/// Applies an approved proposal as the multisig. Any member can execute once
+/// the threshold is met. Pass `event` for event-level actions, `recipient`
+/// for `WithdrawFees` (the fee recipient), `SettleEvent` (the event
+/// treasury), `ProcessRefund` (the requester) and `CancelQueuedAction` (its
+/// rent payer), `vault` for `SettleEvent` (plus payee remaining accounts) and
+/// `ProcessRefund` (plus `ticket` and `refund_request`), `role` (plus
+/// `system_program` when granting) for the role actions, and
+/// `queued_action` (plus `system_program` when queueing) for the timelock
+/// actions. The executing member pays the rent of a newly queued action or
+/// role and gets it back when a role is revoked.
+///
+/// Pauses, unpauses and role revocations go through while the program is
+/// paused, as their single-key instructions do; refunds are blocked by a
+/// REFUND pause and everything else by an ADMIN pause.
+pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let multisig_key = multisig.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let program_state = &mut ctx.accounts.program_state;
+
+    verify_multisig(&proposal.approvals, multisig.threshold, &multisig.members)?;
+
+    match proposal.action.clone() {
+        MultisigAction::Pause { scope, expires_at, reason } => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            pause_program(program_state, multisig_key, scope, expires_at, reason)?;
+        }
+        MultisigAction::Unpause { scope } => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            unpause_program(program_state, multisig_key, scope)?;
+        }
+        MultisigAction::UpdateConfig { platform_fee_basis_points, fee_recipient } => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, None)?;
+            apply_config(program_state, multisig_key, platform_fee_basis_points, fee_recipient)?;
+        }
+        MultisigAction::QueueAction { action } => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, None)?;
+            let queued_action_info = ctx.accounts.queued_action.as_ref().ok_or(TicketError::InvalidAccount)?;
+            let system_program = ctx.accounts.system_program.as_ref().ok_or(TicketError::InvalidAccount)?;
+            let payer = ctx.accounts.member.to_account_info();
+            let queued_action_key = queued_action_info.key();
+            create_queued_action_account(
+                program_state.queued_action_count,
+                queued_action_info,
+                &payer,
+                &system_program.to_account_info(),
+                |queued_action, bump| {
+                    queue(
+                        program_state,
+                        queued_action_key,
+                        queued_action,
+                        action,
+                        multisig_key,
+                        payer.key(),
+                        bump,
+                    )
+                },
+            )?;
+        }
+        MultisigAction::CancelQueuedAction { queued_action } => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            let queued_action_info = ctx.accounts.queued_action.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(queued_action_info.key(), queued_action, TicketError::InvalidAccount);
+            require_keys_eq!(*queued_action_info.owner, crate::ID, TicketError::InvalidAccount);
+            let queued = QueuedAction::try_deserialize(&mut &queued_action_info.try_borrow_data()?[..])?;
+            let payer = ctx.accounts.recipient.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(payer.key(), queued.payer, TicketError::InvalidAccount);
+            emit_cancelled(&queued, queued_action, multisig_key)?;
+            close_account(queued_action_info, payer)?;
+        }
+        MultisigAction::AcceptAuthority => {
+            require_admin_open(program_state, None)?;
+            take_authority(program_state, multisig_key)?;
+        }
+        MultisigAction::WithdrawFees => {
+            require_keys_eq!(program_state.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, None)?;
+            let recipient = ctx.accounts.recipient.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(recipient.key(), program_state.fee_recipient, TicketError::Unauthorized);
+            pay_out_fees(program_state, recipient, multisig_key)?;
+        }
+        MultisigAction::PauseEvent { event, scope, expires_at } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.pause_authority(), multisig_key, TicketError::Unauthorized);
+            pause_event_scopes(event, multisig_key, scope, expires_at)?;
+        }
+        MultisigAction::UnpauseEvent { event, scope } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.pause_authority(), multisig_key, TicketError::Unauthorized);
+            unpause_event_scopes(event, multisig_key, scope)?;
+        }
+        MultisigAction::ProposeEventAuthority { event, new_authority } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            set_pending_event_authority(event, new_authority)?;
+        }
+        MultisigAction::AcceptEventAuthority { event } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_admin_open(program_state, Some(event))?;
+            take_event_authority(event, multisig_key)?;
+        }
+        MultisigAction::SettleEvent { event } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            let vault = ctx.accounts.vault.as_mut().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(vault.event, event.key(), TicketError::InvalidAccount);
+            let treasury = ctx.accounts.recipient.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(treasury.key(), event.treasury, TicketError::Unauthorized);
+            settle(event, vault, treasury, ctx.remaining_accounts)?;
+        }
+        MultisigAction::OpenSales { event } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            set_status(event, EventStatus::OnSale)?;
+        }
+        MultisigAction::PauseSales { event } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            set_status(event, EventStatus::SalesPaused)?;
+        }
+        MultisigAction::ResumeSales { event } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            set_status(event, EventStatus::OnSale)?;
+        }
+        MultisigAction::CancelEvent { event, reason } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            cancel(event, multisig_key, reason)?;
+        }
+        MultisigAction::ProcessRefund { event, ticket } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_open(program_state, Some(event), PauseScope::REFUND)?;
+            let ticket_account = ctx.accounts.ticket.as_mut().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(ticket_account.key(), ticket, TicketError::InvalidAccount);
+            require_keys_eq!(ticket_account.event, event.key(), TicketError::InvalidAccount);
+            let refund_request = ctx.accounts.refund_request.as_mut().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(refund_request.ticket, ticket, TicketError::InvalidAccount);
+            let vault = ctx.accounts.vault.as_mut().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(vault.event, event.key(), TicketError::InvalidAccount);
+            let user = ctx.accounts.recipient.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(user.key(), refund_request.requester, TicketError::Unauthorized);
+            pay_refund(event, refund_request, ticket_account, vault, user, multisig_key)?;
+        }
+        MultisigAction::GrantRole { event, wallet, config } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            let role_info = ctx.accounts.role.as_ref().ok_or(TicketError::InvalidAccount)?;
+            let system_program = ctx.accounts.system_program.as_ref().ok_or(TicketError::InvalidAccount)?;
+            init_role_account(
+                event,
+                wallet,
+                role_info,
+                &ctx.accounts.member.to_account_info(),
+                &system_program.to_account_info(),
+                |role, bump| assign_role(event, role, wallet, config, multisig_key, bump),
+            )?;
+        }
+        MultisigAction::RevokeRole { event, wallet } => {
+            // No pause check, as for revoke_role
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            let role_info = ctx.accounts.role.as_ref().ok_or(TicketError::InvalidAccount)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"role", event.key().as_ref(), wallet.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(role_info.key(), expected, TicketError::InvalidAccount);
+            require_keys_eq!(*role_info.owner, crate::ID, TicketError::InvalidAccount);
+            let role = EventRole::try_deserialize(&mut &role_info.try_borrow_data()?[..])?;
+            emit_revoked(&role, multisig_key)?;
+            close_account(role_info, &ctx.accounts.member.to_account_info())?;
+        }
+        MultisigAction::SetEventTreasury { event, treasury } => {
+            let event = target_event(&mut ctx.accounts.event, event)?;
+            require_keys_eq!(event.authority, multisig_key, TicketError::Unauthorized);
+            require_admin_open(program_state, Some(event))?;
+            change_treasury(event, treasury)?;
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    proposal.executed = true;
+    proposal.executed_at = Some(now);
+
+    emit!(ProposalExecutedEvent {
+        multisig: multisig_key,
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        executed_by: ctx.accounts.member.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn target_event<'a, 'info>(
+    event: &'a mut Option<Account<'info, Event>>,
+    key: Pubkey,
+) -> Result<&'a mut Account<'info, Event>> {
+    let event = event.as_mut().ok_or(TicketError::InvalidAccount)?;
+    require_keys_eq!(event.key(), key, TicketError::InvalidAccount);
+    Ok(event)
+}
+
+/// The ADMIN pause checks the single-key instructions carry as constraints.
+fn require_admin_open(program_state: &ProgramState, event: Option<&Event>) -> Result<()> {
+    require_open(program_state, event, PauseScope::ADMIN)
+}
+
+fn require_open(program_state: &ProgramState, event: Option<&Event>, scope: u8) -> Result<()> {
+    require!(!program_state.is_paused(scope), TicketError::SystemPaused);
+    if let Some(event) = event {
+        require!(!event.is_paused(scope), TicketError::EventPaused);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(multisig_id: u64)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Multisig::LEN,
+        seeds = [b"multisig", creator.key().as_ref(), multisig_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Proposal, approval and execution skip the pause constraints so a multisig
+// authority can still pause and unpause; execute_proposal checks the rest.
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(&proposer.key()) @ TicketError::NotMultisigMember
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::LEN,
+        seeds = [b"proposal", multisig.key().as_ref(), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        constraint = multisig.is_member(&member.key()) @ TicketError::NotMultisigMember
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig @ TicketError::InvalidAccount
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        constraint = multisig.is_member(&member.key()) @ TicketError::NotMultisigMember
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig @ TicketError::InvalidAccount,
+        constraint = !proposal.executed @ TicketError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Target of event-level actions
+    #[account(mut)]
+    pub event: Option<Account<'info, Event>>,
+
+    /// Vault of the event being settled or refunded
+    #[account(mut)]
+    pub vault: Option<Account<'info, EventVault>>,
+
+    /// Ticket being refunded
+    #[account(mut)]
+    pub ticket: Option<Account<'info, Ticket>>,
+
+    /// Request being paid, checked against `ticket`
+    #[account(mut)]
+    pub refund_request: Option<Account<'info, RefundRequest>>,
+
+    /// CHECK: Fee recipient, event treasury, refund requester or rent payer, checked against the action
+    #[account(mut)]
+    pub recipient: Option<AccountInfo<'info>>,
+
+    /// CHECK: QueuedAction PDA, created or checked against the action
+    #[account(mut)]
+    pub queued_action: Option<AccountInfo<'info>>,
+
+    /// CHECK: EventRole PDA, created or checked against the action
+    #[account(mut)]
+    pub role: Option<AccountInfo<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[event]
+pub struct MultisigCreatedEvent {
+    pub multisig: Pubkey,
+    pub creator: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
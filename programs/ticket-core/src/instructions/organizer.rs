@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::state::{Organizer, RefundPolicy};
+use crate::errors::TicketError;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Creates the signer's organizer account. Required before `create_event`.
+pub fn register_organizer(
+    ctx: Context<RegisterOrganizer>,
+    default_treasury: Pubkey,
+    default_refund_policy: RefundPolicy,
+) -> Result<()> {
+    require!(default_treasury != Pubkey::default(), TicketError::InvalidAccount);
+    default_refund_policy.validate()?;
+
+    let organizer = &mut ctx.accounts.organizer;
+    organizer.authority = ctx.accounts.authority.key();
+    organizer.event_count = 0;
+    organizer.default_treasury = default_treasury;
+    organizer.default_refund_policy = default_refund_policy;
+    organizer.verified = false;
+    organizer.created_at = Clock::get()?.unix_timestamp;
+    organizer.bump = ctx.bumps.organizer;
+
+    emit!(OrganizerRegisteredEvent {
+        organizer: organizer.key(),
+        authority: organizer.authority,
+        timestamp: organizer.created_at,
+    });
+
+    Ok(())
+}
+
+/// Changes the defaults for events created from now on; existing events keep theirs.
+pub fn update_organizer(
+    ctx: Context<UpdateOrganizer>,
+    default_treasury: Option<Pubkey>,
+    default_refund_policy: Option<RefundPolicy>,
+) -> Result<()> {
+    let organizer = &mut ctx.accounts.organizer;
+
+    if let Some(treasury) = default_treasury {
+        require!(treasury != Pubkey::default(), TicketError::InvalidAccount);
+        organizer.default_treasury = treasury;
+    }
+    if let Some(policy) = default_refund_policy {
+        policy.validate()?;
+        organizer.default_refund_policy = policy;
+    }
+
+    emit!(OrganizerUpdatedEvent {
+        organizer: organizer.key(),
+        default_treasury: organizer.default_treasury,
+        default_refund_policy: organizer.default_refund_policy.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterOrganizer<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Organizer::LEN,
+        seeds = [b"organizer", authority.key().as_ref()],
+        bump
+    )]
+    pub organizer: Account<'info, Organizer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateOrganizer<'info> {
+    #[account(
+        mut,
+        seeds = [b"organizer", authority.key().as_ref()],
+        bump = organizer.bump,
+        has_one = authority @ TicketError::Unauthorized
+    )]
+    pub organizer: Account<'info, Organizer>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct OrganizerRegisteredEvent {
+    pub organizer: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Carries the defaults after the update, changed or not.
+#[event]
+pub struct OrganizerUpdatedEvent {
+    pub organizer: Pubkey,
+    pub default_treasury: Pubkey,
+    pub default_refund_policy: RefundPolicy,
+    pub timestamp: i64,
+}
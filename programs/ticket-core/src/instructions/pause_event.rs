@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::errors::TicketError;
+use crate::traits::Pausable;
+
+/// Circuit breaker for a single event. While a scope is paused, instructions
+/// of that class on the event or its tickets fail with `EventPaused`.
+pub fn pause_event(ctx: Context<PauseEventCtx>, scope: u8, expires_at: Option<i64>) -> Result<()> {
+    pause_event_scopes(&mut ctx.accounts.event, ctx.accounts.authority.key(), scope, expires_at)
+}
+
+pub fn unpause_event(ctx: Context<PauseEventCtx>, scope: u8) -> Result<()> {
+    unpause_event_scopes(&mut ctx.accounts.event, ctx.accounts.authority.key(), scope)
+}
+
+pub(crate) fn pause_event_scopes(
+    event: &mut Account<Event>,
+    authority: Pubkey,
+    scope: u8,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    event.pause(scope, expires_at)?;
+
+    emit!(EventPausedEvent {
+        event: event.key(),
+        authority,
+        scope,
+        paused_scopes: event.pause_state.scopes,
+        expires_at,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn unpause_event_scopes(event: &mut Account<Event>, authority: Pubkey, scope: u8) -> Result<()> {
+    event.unpause(scope)?;
+
+    emit!(EventUnpausedEvent {
+        event: event.key(),
+        authority,
+        scope,
+        paused_scopes: event.pause_state.scopes,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseEventCtx<'info> {
+    #[account(
+        mut,
+        constraint = event.pause_authority() == authority.key() @ TicketError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct EventPausedEvent {
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub scope: u8,
+    pub paused_scopes: u8,
+    pub expires_at: Option<i64>,            // Expiry given for `scope`
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EventUnpausedEvent {
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub scope: u8,
+    pub paused_scopes: u8,
+    pub timestamp: i64,
+}
@@ -1,31 +1,103 @@
-use crate::state::{Event, Ticket, RefundRequest};
+use crate::state::{Event, EventAction, EventVault, Ticket};
+use super::request_refund::{RefundRequest, RefundStatus};
 use crate::errors::TicketError;
+use crate::utils::math::*;
 use anchor_lang::prelude::*;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+pub fn process_refund(ctx: Context<ProcessRefund>) -> Result<()> {
+    pay_refund(
+        &ctx.accounts.event,
+        &mut ctx.accounts.refund_request,
+        &mut ctx.accounts.ticket,
+        &mut ctx.accounts.vault,
+        &ctx.accounts.user.to_account_info(),
+        ctx.accounts.authority.key(),
+    )
+}
+
+/// Pays a pending refund out of the vault. The caller has tied the request,
+/// ticket, vault and user to `event`.
+pub(crate) fn pay_refund<'info>(
+    event: &Event,
+    refund_request: &mut RefundRequest,
+    ticket: &mut Ticket,
+    vault: &mut Account<'info, EventVault>,
+    user: &AccountInfo<'info>,
+    processor: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    event.guard(EventAction::PayRefund)?;
+    require!(
+        refund_request.status == RefundStatus::Pending,
+        TicketError::InvalidRefundStatus
+    );
+
+    // Refunds are only ever paid from the event vault
+    let amount = refund_request.amount;
+    EventVault::pay_out(&vault.to_account_info(), user, amount)?;
+    vault.total_refunded = safe_add(vault.total_refunded, amount)?;
+    vault.pending_refunds = safe_sub(vault.pending_refunds, amount)?;
+
+    refund_request.status = RefundStatus::Approved;
+    refund_request.processed_at = Some(clock.unix_timestamp);
+    refund_request.processor = Some(processor);
+
+    ticket.refunded_at = Some(clock.unix_timestamp);
+    ticket.refund_amount = Some(amount);
+    ticket.refund_reason = Some(refund_request.reason.clone());
+
+    msg!("Refund of {} processed for ticket {}", amount, ticket.ticket_id);
 
-pub fn process_refund(_ctx: Context<ProcessRefund>) -> Result<()> {
-    // TODO: Implement refund processing logic
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ProcessRefund<'info> {
     #[account(
-        has_one = authority @ TicketError::Unauthorized
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::REFUND) @ TicketError::EventPaused
     )]
     pub event: Account<'info, Event>,
-    
+
     /// CHECK: Event authority must sign to process refunds
     pub authority: Signer<'info>,
-    
-    #[account(mut)]
+
+    #[account(
+        mut,
+        seeds = [b"refund", ticket.key().as_ref()],
+        bump
+    )]
     pub refund_request: Account<'info, RefundRequest>,
-    
-    #[account(mut)]
+
+    #[account(
+        mut,
+        constraint = ticket.event == event.key() @ TicketError::InvalidAccount
+    )]
     pub ticket: Account<'info, Ticket>,
-    
+
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
     /// CHECK: User receiving the refund
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = user.key() == refund_request.requester @ TicketError::Unauthorized
+    )]
     pub user: UncheckedAccount<'info>,
-    
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::REFUND) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
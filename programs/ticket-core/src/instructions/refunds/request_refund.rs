@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::TicketError;
 use crate::utils::math::*;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
 
 #[derive(Accounts)]
 pub struct RequestRefund<'info> {
@@ -12,15 +14,17 @@ pub struct RequestRefund<'info> {
         constraint = ticket.owner == buyer.key() @ TicketError::NotTicketOwner,
         constraint = !ticket.used @ TicketError::TicketAlreadyUsed,
         constraint = ticket.status == TicketStatus::Valid @ TicketError::RefundNotEligible,
-        constraint = ticket.refund_eligible @ TicketError::RefundNotEligible
+        // Cancellation refunds every paid ticket, whatever its policy said
+        constraint = ticket.refund_eligible
+            || (event.status == EventStatus::Cancelled && !ticket.is_comp) @ TicketError::RefundNotEligible
     )]
     pub ticket: Account<'info, Ticket>,
 
     #[account(
         mut,
-        seeds = [b"event", event.event_id.to_le_bytes().as_ref()],
+        seeds = [b"event", event.organizer_account.as_ref(), event.event_id.to_le_bytes().as_ref()],
         bump,
-        constraint = !event.cancelled @ TicketError::EventCancelled
+        constraint = !event.is_paused(PauseScope::REFUND) @ TicketError::EventPaused
     )]
     pub event: Account<'info, Event>,
 
@@ -36,12 +40,36 @@ pub struct RequestRefund<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
 
-    /// CHECK: Event treasury that holds funds
+    /// Event escrow that holds ticket revenue
     #[account(
         mut,
-        constraint = event_treasury.key() == event.treasury @ TicketError::Unauthorized
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
     )]
-    pub event_treasury: AccountInfo<'info>,
+    pub vault: Account<'info, EventVault>,
+
+    /// Purchase record of the wallet that paid for the ticket
+    #[account(
+        mut,
+        seeds = [b"purchase", event.key().as_ref(), ticket.purchaser.as_ref()],
+        bump = purchase_record.bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// Seat held by the ticket; closed to free it up for resale
+    #[account(
+        mut,
+        close = buyer,
+        constraint = seat.ticket == ticket.key() @ TicketError::InvalidAccount
+    )]
+    pub seat: Option<Account<'info, SeatClaim>>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::REFUND) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
 
     pub system_program: Program<'info, System>,
 }
@@ -79,26 +107,20 @@ pub fn request_refund(
     let refund_request = &mut ctx.accounts.refund_request;
     let clock = Clock::get()?;
 
-    // Check refund deadline
-    if let Some(deadline) = ticket.refund_deadline {
-        require!(
-            clock.unix_timestamp < deadline,
-            TicketError::RefundWindowClosed
-        );
-    } else {
-        // Use event start time minus refund window
-        require!(
-            clock.unix_timestamp < event.start_time - REFUND_WINDOW_SECONDS,
-            TicketError::RefundWindowClosed
-        );
-    }
+    event.guard(EventAction::Refund)?;
 
-    // Calculate refund amount based on policy
-    let refund_amount = calculate_refund_amount(
+    // Fall back to event start time minus refund window
+    let deadline = ticket
+        .refund_deadline
+        .unwrap_or(event.start_time - REFUND_WINDOW_SECONDS);
+    let refund_amount = refund_due(
+        event.status,
         &event.refund_policy,
         ticket.purchase_price,
+        ticket.platform_fee,
+        deadline,
         event.start_time,
-        clock.unix_timestamp
+        clock.unix_timestamp,
     )?;
 
     // Create refund request
@@ -109,21 +131,25 @@ pub fn request_refund(
     refund_request.requested_at = clock.unix_timestamp;
     refund_request.status = RefundStatus::Pending;
 
-    // Update ticket status
+    // Update ticket status, releasing any reserved seat
+    require!(
+        ticket.seat.is_none() || ctx.accounts.seat.is_some(),
+        TicketError::InvalidAccount
+    );
     ticket.status = TicketStatus::Refunded;
     ticket.refund_eligible = false;
+    ticket.seat = None;
 
     // Process immediate refund if amount is small
+    let vault = &mut ctx.accounts.vault;
     if refund_amount < 1_000_000 { // 0.001 SOL
-        // Transfer refund immediately
-        **ctx.accounts.buyer.lamports.borrow_mut() = safe_add(
-            ctx.accounts.buyer.lamports(),
-            refund_amount
-        )?;
-        **ctx.accounts.event_treasury.lamports.borrow_mut() = safe_sub(
-            ctx.accounts.event_treasury.lamports(),
-            refund_amount
+        // Pay the refund out of the event vault
+        EventVault::pay_out(
+            &vault.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            refund_amount,
         )?;
+        vault.total_refunded = safe_add(vault.total_refunded, refund_amount)?;
 
         refund_request.status = RefundStatus::Approved;
         refund_request.processed_at = Some(clock.unix_timestamp);
@@ -131,10 +157,14 @@ pub fn request_refund(
         ticket.refunded_at = Some(clock.unix_timestamp);
         ticket.refund_amount = Some(refund_amount);
         ticket.refund_reason = Some(refund_request.reason.clone());
+    } else {
+        // Held back from settlement until process_refund pays it
+        vault.pending_refunds = safe_add(vault.pending_refunds, refund_amount)?;
     }
 
-    // Update event stats
+    // Update event stats and give the purchaser their allowance back
     event.tickets_sold = safe_sub(event.tickets_sold as u64, 1)? as u32;
+    ctx.accounts.purchase_record.record_refund();
     if ticket.tier_index < event.tiers.len() {
         event.tiers[ticket.tier_index].refunded_count = safe_add(
             event.tiers[ticket.tier_index].refunded_count as u64,
@@ -147,6 +177,28 @@ pub fn request_refund(
     Ok(())
 }
 
+/// What a ticket gets back if refunded at `now`. A cancelled event refunds
+/// everything that was escrowed, with no deadline; otherwise the policy
+/// applies up to `deadline`. The platform fee was never escrowed, so it is
+/// never refundable.
+pub(crate) fn refund_due(
+    status: EventStatus,
+    policy: &RefundPolicy,
+    purchase_price: u64,
+    platform_fee: u64,
+    deadline: i64,
+    event_start: i64,
+    now: i64,
+) -> Result<u64> {
+    let escrowed = safe_sub(purchase_price, platform_fee)?;
+    if status == EventStatus::Cancelled {
+        return Ok(escrowed);
+    }
+
+    require!(now < deadline, TicketError::RefundWindowClosed);
+    Ok(calculate_refund_amount(policy, purchase_price, event_start, now)?.min(escrowed))
+}
+
 fn calculate_refund_amount(
     policy: &RefundPolicy,
     purchase_price: u64,
@@ -191,3 +243,45 @@ fn calculate_refund_amount(
 }
 
 use crate::constants::REFUND_WINDOW_SECONDS;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000_000;
+    const PRICE: u64 = 1_000;
+    const FEE: u64 = 25;
+
+    #[test]
+    fn cancelled_event_refunds_in_full() {
+        let mut status = EventStatus::OnSale;
+        assert!(status.can_transition_to(EventStatus::Cancelled));
+        status = EventStatus::Cancelled;
+        assert!(status.allows(EventAction::Refund));
+
+        // Past the deadline, on a no-refunds policy, the buyer still gets
+        // back everything that was escrowed
+        let now = START - 60;
+        let deadline = START - REFUND_WINDOW_SECONDS;
+        assert_eq!(
+            refund_due(status, &RefundPolicy::NoRefunds, PRICE, FEE, deadline, START, now).unwrap(),
+            PRICE - FEE
+        );
+    }
+
+    #[test]
+    fn live_event_keeps_deadline_and_policy() {
+        let policy = RefundPolicy::FullRefund { hours_before_event: 48 };
+        let deadline = policy.refund_deadline(START).unwrap();
+
+        assert!(refund_due(EventStatus::OnSale, &policy, PRICE, FEE, deadline, START, deadline).is_err());
+        assert_eq!(
+            refund_due(EventStatus::OnSale, &policy, PRICE, FEE, deadline, START, deadline - 1).unwrap(),
+            PRICE - FEE
+        );
+        assert_eq!(
+            refund_due(EventStatus::OnSale, &RefundPolicy::NoRefunds, PRICE, FEE, deadline, START, 0).unwrap(),
+            0
+        );
+    }
+}
@@ -1,36 +1,162 @@
-use anchor_lang::prelude::*;
-use crate::{state::*, errors::TicketError};
-
-pub fn reserve_tickets(
-    ctx: Context<ReserveTicketsCtx>,
-    quantity: u32,
-) -> Result<()> {
-    let event = &mut ctx.accounts.event;
-    
-    // Only event authority can reserve tickets
-    require!(
-        event.authority == ctx.accounts.authority.key(),
-        TicketError::Unauthorized
-    );
-    
-    // Check if we have enough capacity
-    if event.tickets_sold + quantity > event.total_tickets {
-        return Err(TicketError::InsufficientCapacity.into());
-    }
-    
-    // Reserve tickets (no payment needed - these are for artist/venue)
-    event.tickets_sold += quantity;
-    
-    msg!("Reserved {} tickets for event {}", quantity, event.name);
-    
-    Ok(())
-}
-
-#[derive(Accounts)]
-pub struct ReserveTicketsCtx<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(mut)]
-    pub event: Account<'info, Event>,
-}
\ No newline at end of file
+use crate::utils::{accounts::create_ticket_account, math::*};
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::TicketError, constants::MAX_BATCH_SIZE};
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Issues zero-price comp tickets for artist, venue and guest-list
+/// allocations. Ticket PDAs for the next sequential ids are passed as
+/// remaining accounts. `recipients` is either empty (everything goes to the
+/// unassigned pool) or holds one wallet per ticket, where `Pubkey::default()`
+/// leaves that ticket in the pool for `assign_comp`.
+pub fn reserve_tickets<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReserveTicketsCtx<'info>>,
+    tier_index: u8,
+    quantity: u32,
+    recipients: Vec<Pubkey>,
+) -> Result<()> {
+    let tier_index = tier_index as usize;
+    let clock = Clock::get()?;
+
+    ctx.accounts.event.guard(EventAction::Comp)?;
+    require!(quantity > 0, TicketError::InvalidTicketCount);
+    if quantity > MAX_BATCH_SIZE {
+        return Err(TicketError::BatchSizeTooLarge.into());
+    }
+    require!(
+        recipients.is_empty() || recipients.len() == quantity as usize,
+        TicketError::InvalidRecipientCount
+    );
+    require!(
+        ctx.remaining_accounts.len() == quantity as usize,
+        TicketError::InvalidAccount
+    );
+
+    // Comps use up capacity but are not sales; seated tiers go through mint_ticket
+    ctx.accounts.event.require_unseated(tier_index)?;
+    ctx.accounts.event.record_comps(tier_index, quantity)?;
+
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    for (i, ticket_info) in ctx.remaining_accounts.iter().enumerate() {
+        let recipient = recipients.get(i).copied().unwrap_or_default();
+        let event = &ctx.accounts.event;
+        let ticket = create_ticket_account(event, ticket_info, &authority, &system_program, |ticket, bump| {
+            ticket.issue(event, Pubkey::default(), tier_index, 0, clock.unix_timestamp, bump);
+            ticket.is_comp = true;
+            ticket.refund_eligible = false;
+            ticket.refund_deadline = None;
+            if recipient != Pubkey::default() {
+                ticket.assign_comp(authority.key(), recipient, clock.unix_timestamp);
+            }
+            Ok(())
+        })?;
+
+        let event = &mut ctx.accounts.event;
+        event.next_ticket_id = safe_add(event.next_ticket_id, 1)?;
+
+        emit!(CompIssuedEvent {
+            event: event.key(),
+            ticket: ticket_info.key(),
+            ticket_id: ticket.ticket_id,
+            owner: ticket.owner,
+            tier_index: tier_index as u8,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Reserved {} comp tickets for event {}", quantity, ctx.accounts.event.name);
+
+    Ok(())
+}
+
+/// Assigns a comp from the unassigned pool to a wallet.
+pub fn assign_comp(ctx: Context<AssignCompCtx>, recipient: Pubkey) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+
+    ctx.accounts.event.guard(EventAction::Comp)?;
+    require!(ticket.is_comp, TicketError::NotCompTicket);
+    require!(ticket.owner == Pubkey::default(), TicketError::CompAlreadyAssigned);
+    require!(recipient != Pubkey::default(), TicketError::InvalidAccount);
+
+    ticket.assign_comp(ctx.accounts.authority.key(), recipient, clock.unix_timestamp);
+
+    emit!(CompAssignedEvent {
+        event: ticket.event,
+        ticket: ticket.key(),
+        ticket_id: ticket.ticket_id,
+        owner: recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReserveTicketsCtx<'info> {
+    /// Pays rent for the comp ticket accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AssignCompCtx<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::MINT) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        constraint = ticket.event == event.key() @ TicketError::InvalidAccount,
+        constraint = ticket.status == TicketStatus::Valid @ TicketError::TicketNotValid
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct CompIssuedEvent {
+    pub event: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub owner: Pubkey,                      // Default pubkey while unassigned
+    pub tier_index: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompAssignedEvent {
+    pub event: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, Payee};
+use crate::errors::TicketError;
+use crate::utils::validation::validate_payees;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Replaces the event's payee list. Only allowed before the first sale.
+pub fn set_revenue_splits(ctx: Context<SetRevenueSplits>, payees: Vec<Payee>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+
+    event.guard(EventAction::Configure)?;
+    require!(!event.sales_started(), TicketError::RevenueSplitLocked);
+    validate_payees(&payees)?;
+
+    event.payees = payees;
+    event.updated_at = clock.unix_timestamp;
+
+    emit!(RevenueSplitsUpdatedEvent {
+        event: event.key(),
+        payees: event.payees.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRevenueSplits<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct RevenueSplitsUpdatedEvent {
+    pub event: Pubkey,
+    pub payees: Vec<Payee>,
+    pub timestamp: i64,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, SeatingMap, Section};
+use crate::errors::TicketError;
+use crate::constants::{MAX_SECTIONS, MAX_SECTION_NAME_LEN};
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Sets up reserved seating. Every tier referenced by a section becomes seated
+/// and must not have sold anything yet.
+pub fn create_seating_map(ctx: Context<CreateSeatingMap>, sections: Vec<Section>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let seating_map = &mut ctx.accounts.seating_map;
+    let clock = Clock::get()?;
+
+    event.guard(EventAction::Configure)?;
+    require!(
+        !sections.is_empty() && sections.len() <= MAX_SECTIONS,
+        TicketError::InvalidSeatingMap
+    );
+
+    for section in &sections {
+        require!(
+            !section.name.is_empty() && section.name.len() <= MAX_SECTION_NAME_LEN,
+            TicketError::InvalidSeatingMap
+        );
+        require!(
+            section.rows > 0 && section.seats_per_row > 0,
+            TicketError::InvalidSeatingMap
+        );
+
+        let tier = event
+            .tiers
+            .get_mut(section.tier_index as usize)
+            .ok_or(TicketError::InvalidTier)?;
+        require!(
+            tier.minted_count == 0 && tier.held_count == 0,
+            TicketError::InvalidSeatingMap
+        );
+        tier.seated = true;
+    }
+
+    seating_map.event = event.key();
+    seating_map.sections = sections;
+    seating_map.bump = ctx.bumps.seating_map;
+
+    event.seating_map = Some(seating_map.key());
+    event.updated_at = clock.unix_timestamp;
+
+    emit!(SeatingMapCreatedEvent {
+        event: event.key(),
+        seating_map: seating_map.key(),
+        sections: seating_map.sections.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateSeatingMap<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeatingMap::LEN,
+        seeds = [b"seating_map", event.key().as_ref()],
+        bump
+    )]
+    pub seating_map: Account<'info, SeatingMap>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SeatingMapCreatedEvent {
+    pub event: Pubkey,
+    pub seating_map: Pubkey,
+    pub sections: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,433 @@
+use crate::utils::{accounts::collect_payment, math::*};
+use anchor_lang::prelude::*;
+use crate::{state::{*, program_state::ProgramState}, errors::TicketError};
+use crate::constants::{MAX_SERIES_EVENTS, MAX_SERIES_NAME_LEN};
+use crate::traits::{Pausable, PauseScope};
+
+pub fn create_series(
+    ctx: Context<CreateSeries>,
+    series_id: u64,
+    name: String,
+    pass_price: u64,
+    pass_supply: u32,
+) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= MAX_SERIES_NAME_LEN,
+        TicketError::NameTooLong
+    );
+    require!(pass_price > 0, TicketError::InvalidTicketPrice);
+    require!(pass_supply > 0, TicketError::InvalidTicketCount);
+
+    let clock = Clock::get()?;
+    let series = &mut ctx.accounts.series;
+    series.authority = ctx.accounts.authority.key();
+    series.treasury = ctx.accounts.authority.key();
+    series.series_id = series_id;
+    series.name = name;
+    series.pass_price = pass_price;
+    series.pass_supply = pass_supply;
+    series.created_at = clock.unix_timestamp;
+    series.bump = ctx.bumps.series;
+
+    // Pass revenue is escrowed the same way as event revenue
+    let vault = &mut ctx.accounts.vault;
+    vault.event = series.key();
+    vault.bump = ctx.bumps.vault;
+
+    msg!("Series {} created with {} passes", series.name, pass_supply);
+
+    Ok(())
+}
+
+/// Adds an event to the series, setting aside `pass_supply` of its capacity
+/// for pass holders.
+pub fn add_series_event(ctx: Context<AddSeriesEvent>) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let event = &mut ctx.accounts.event;
+
+    event.guard(EventAction::Configure)?;
+    // Pass refunds are shares of the events a pass was sold for
+    require!(series.passes_sold == 0, TicketError::SeriesLocked);
+    require!(event.series.is_none(), TicketError::EventAlreadyInSeries);
+    require!(
+        series.events.len() < MAX_SERIES_EVENTS,
+        TicketError::TooManySeriesEvents
+    );
+    require!(
+        series.pass_supply <= event.available_capacity(),
+        TicketError::EventFull
+    );
+
+    event.series = Some(series.key());
+    event.series_reserved = series.pass_supply;
+    event.updated_at = Clock::get()?.unix_timestamp;
+
+    series.events.push(event.key());
+    series.pass_refunds.push(0);
+    series.ends_at = series.ends_at.max(event.start_time.max(event.end_time));
+
+    emit!(SeriesEventAddedEvent {
+        series: series.key(),
+        event: event.key(),
+        event_index: (series.events.len() - 1) as u8,
+        reserved: series.pass_supply,
+    });
+
+    Ok(())
+}
+
+/// Sells one pass. Remaining accounts are the member events, in
+/// `series.events` order; every one must still be ahead, since a pass for a
+/// cancelled or finished day could not be used there.
+pub fn buy_series_pass<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuySeriesPass<'info>>,
+) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let pass = &mut ctx.accounts.pass;
+    let clock = Clock::get()?;
+
+    require!(!series.events.is_empty(), TicketError::EventNotInSeries);
+    require!(series.passes_sold < series.pass_supply, TicketError::SeriesPassSoldOut);
+    for event in member_events(series, ctx.remaining_accounts)? {
+        require_pass_sellable(&event, clock.unix_timestamp)?;
+    }
+
+    // Take the platform fee and escrow the rest in the series vault
+    let platform_fee = collect_payment(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        series.pass_price,
+    )?;
+
+    pass.series = series.key();
+    pass.pass_id = series.next_pass_id;
+    pass.owner = ctx.accounts.buyer.key();
+    pass.purchase_price = series.pass_price;
+    pass.platform_fee = platform_fee;
+    pass.purchased_at = clock.unix_timestamp;
+    pass.bump = ctx.bumps.pass;
+
+    series.passes_sold += 1;
+    series.next_pass_id = safe_add(series.next_pass_id, 1)?;
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_tickets_sold = safe_add(program_state.total_tickets_sold, 1)?;
+
+    emit!(SeriesPassPurchasedEvent {
+        series: series.key(),
+        pass: pass.key(),
+        pass_id: pass.pass_id,
+        owner: pass.owner,
+        purchase_price: pass.purchase_price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Loads the member events passed as remaining accounts, which must be
+/// exactly `series.events`, in order.
+fn member_events<'info>(
+    series: &EventSeries,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<Account<'info, Event>>> {
+    require!(accounts.len() == series.events.len(), TicketError::InvalidAccount);
+    series
+        .events
+        .iter()
+        .zip(accounts)
+        .map(|(member, info)| {
+            require_keys_eq!(info.key(), *member, TicketError::InvalidAccount);
+            Account::<Event>::try_from(info)
+        })
+        .collect()
+}
+
+/// Fails unless `event` could sell a ticket now: on sale or live, not over,
+/// and without paused sales.
+pub(crate) fn require_pass_sellable(event: &Event, now: i64) -> Result<()> {
+    event.guard(EventAction::Sell)?;
+    require!(now < event.ends_at(), TicketError::InvalidEventStatus);
+    require!(!event.is_paused(PauseScope::MINT), TicketError::EventPaused);
+    Ok(())
+}
+
+/// Pays a pass holder back their share of a cancelled member event. The pass
+/// stays valid for the other events.
+pub fn refund_series_pass(ctx: Context<RefundSeriesPass>) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let pass = &mut ctx.accounts.pass;
+    let event = &ctx.accounts.event;
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    event.guard(EventAction::Refund)?;
+    require!(event.status == EventStatus::Cancelled, TicketError::RefundNotEligible);
+    let event_index = series
+        .event_index(&event.key())
+        .ok_or(TicketError::EventNotInSeries)?;
+    require!(!pass.is_refunded(event_index), TicketError::RefundNotEligible);
+
+    let amount = series.pass_refund(safe_sub(pass.purchase_price, pass.platform_fee)?);
+    EventVault::pay_out(
+        &vault.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        amount,
+    )?;
+    vault.total_refunded = safe_add(vault.total_refunded, amount)?;
+
+    pass.record_refund(event_index);
+    series.pass_refunds[event_index] = safe_add(series.pass_refunds[event_index] as u64, 1)? as u32;
+
+    emit!(SeriesPassRefundedEvent {
+        series: series.key(),
+        pass: pass.key(),
+        event: event.key(),
+        owner: pass.owner,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Releases escrowed pass revenue to the series treasury once every member
+/// event is over. Remaining accounts are the member events, in
+/// `series.events` order; refunds still owed for cancelled ones stay in the
+/// vault.
+pub fn settle_series<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleSeries<'info>>,
+) -> Result<()> {
+    let series = &ctx.accounts.series;
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(!series.events.is_empty(), TicketError::EventNotInSeries);
+    require!(clock.unix_timestamp >= series.ends_at, TicketError::EventNotEnded);
+
+    let mut owed: u64 = 0;
+    for (i, event) in member_events(series, ctx.remaining_accounts)?.iter().enumerate() {
+        if event.status == EventStatus::Cancelled {
+            let unrefunded = series.passes_sold.saturating_sub(series.pass_refunds[i]);
+            let per_pass = series.pass_refund(series.pass_price);
+            owed = safe_add(owed, safe_mul(per_pass, unrefunded as u64)?)?;
+        }
+    }
+
+    let amount = vault.settleable()?.saturating_sub(owed);
+    require!(amount > 0, TicketError::InsufficientFunds);
+
+    EventVault::pay_out(
+        &vault.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        amount,
+    )?;
+    vault.total_settled = safe_add(vault.total_settled, amount)?;
+
+    emit!(SeriesSettledEvent {
+        series: series.key(),
+        treasury: series.treasury,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct CreateSeries<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EventSeries::LEN,
+        seeds = [b"series", authority.key().as_ref(), series_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EventVault::LEN,
+        seeds = [b"vault", series.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddSeriesEvent<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct BuySeriesPass<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut)]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = SeriesPass::LEN,
+        seeds = [b"series_pass", series.key().as_ref(), series.next_pass_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pass: Account<'info, SeriesPass>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", series.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::MINT) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundSeriesPass<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        mut,
+        seeds = [b"series_pass", series.key().as_ref(), pass.pass_id.to_le_bytes().as_ref()],
+        bump = pass.bump,
+        constraint = pass.owner == owner.key() @ TicketError::NotTicketOwner
+    )]
+    pub pass: Account<'info, SeriesPass>,
+
+    /// The cancelled member event being refunded
+    #[account(
+        constraint = !event.is_paused(PauseScope::REFUND) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", series.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::REFUND) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[derive(Accounts)]
+pub struct SettleSeries<'info> {
+    #[account(
+        has_one = authority @ TicketError::Unauthorized,
+        has_one = treasury @ TicketError::Unauthorized
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", series.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    /// CHECK: Payout destination, must match series.treasury
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct SeriesEventAddedEvent {
+    pub series: Pubkey,
+    pub event: Pubkey,
+    pub event_index: u8,
+    pub reserved: u32,
+}
+
+#[event]
+pub struct SeriesPassPurchasedEvent {
+    pub series: Pubkey,
+    pub pass: Pubkey,
+    pub pass_id: u64,
+    pub owner: Pubkey,
+    pub purchase_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeriesPassRefundedEvent {
+    pub series: Pubkey,
+    pub pass: Pubkey,
+    pub event: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeriesSettledEvent {
+    pub series: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, EventStatus, EventVault};
+use crate::errors::TicketError;
+use super::event_status::set_status;
+use crate::utils::math::*;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Releases escrowed revenue once the event is over. Pending refunds stay in
+/// the vault until they are processed.
+///
+/// Without a revenue split everything goes to the event treasury. With one,
+/// the payee wallets are passed as remaining accounts in `event.payees` order
+/// and each receives its share; rounding dust goes to the last payee.
+pub fn settle_event<'info>(ctx: Context<'_, '_, 'info, 'info, SettleEvent<'info>>) -> Result<()> {
+    settle(
+        &mut ctx.accounts.event,
+        &mut ctx.accounts.vault,
+        &ctx.accounts.treasury,
+        ctx.remaining_accounts,
+    )
+}
+
+pub(crate) fn settle<'info>(
+    event: &mut Account<'info, Event>,
+    vault: &mut Account<'info, EventVault>,
+    treasury: &AccountInfo<'info>,
+    payee_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The first settlement closes the event; settling again later pays out
+    // whatever is still left in the vault
+    event.guard(EventAction::Settle)?;
+    if event.status == EventStatus::Ended {
+        set_status(event, EventStatus::Settled)?;
+    }
+
+    let amount = vault.settleable()?;
+    require!(amount > 0, TicketError::InsufficientFunds);
+
+    let vault_info = vault.to_account_info();
+    if event.payees.is_empty() {
+        EventVault::pay_out(&vault_info, treasury, amount)?;
+    } else {
+        require!(
+            payee_accounts.len() == event.payees.len(),
+            TicketError::InvalidAccount
+        );
+
+        let mut remaining = amount;
+        let last = event.payees.len() - 1;
+        for (i, (payee, payee_info)) in event.payees.iter().zip(payee_accounts).enumerate() {
+            require_keys_eq!(payee_info.key(), payee.wallet, TicketError::InvalidAccount);
+
+            let share = if i == last {
+                remaining
+            } else {
+                calculate_percentage(amount, payee.basis_points)?
+            };
+            remaining = safe_sub(remaining, share)?;
+
+            EventVault::pay_out(&vault_info, payee_info, share)?;
+
+            emit!(PayeePaidEvent {
+                event: event.key(),
+                payee: payee.wallet,
+                amount: share,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+    vault.total_settled = safe_add(vault.total_settled, amount)?;
+
+    emit!(EventSettledEvent {
+        event: event.key(),
+        treasury: event.treasury,
+        amount,
+        total_settled: vault.total_settled,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Settled {} lamports for event {}", amount, event.name);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleEvent<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TicketError::Unauthorized,
+        has_one = treasury @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", event.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    /// CHECK: Payout destination, must match event.treasury
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct EventSettledEvent {
+    pub event: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_settled: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayeePaidEvent {
+    pub event: Pubkey,
+    pub payee: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
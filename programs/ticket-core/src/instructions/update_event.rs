@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::{Event, EventUpdatedEvent};
+use crate::state::{Event, EventAction, EventStatus, EventUpdatedEvent};
 use crate::errors::TicketError;
 use crate::utils::validation::*;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
 
 pub fn update_event(
     ctx: Context<UpdateEvent>,
@@ -9,16 +11,13 @@ pub fn update_event(
     description: Option<String>,
     venue: Option<String>,
     event_date: Option<i64>,
-    ticket_price: Option<u64>,
+    max_tickets_per_wallet: Option<u16>,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
     let clock = Clock::get()?;
     
-    // Check event hasn't started
-    require!(
-        clock.unix_timestamp < event.start_time,
-        TicketError::EventAlreadyStarted
-    );
+    // Details are locked once the event goes live
+    event.guard(EventAction::Configure)?;
     
     // Update fields if provided
     if let Some(new_name) = name {
@@ -45,9 +44,11 @@ pub fn update_event(
         event.start_time = new_date;
     }
     
-    if let Some(new_price) = ticket_price {
-        validate_ticket_price(new_price)?;
-        event.base_price = new_price;
+    // Buyers must know the cap before the first sale
+    if let Some(max) = max_tickets_per_wallet {
+        require!(event.status == EventStatus::Draft, TicketError::InvalidEventStatus);
+        validate_wallet_limit(Some(max))?;
+        event.max_tickets_per_wallet = Some(max);
     }
     
     event.updated_at = clock.unix_timestamp;
@@ -60,13 +61,53 @@ pub fn update_event(
     Ok(())
 }
 
+/// Points settlement payouts at a new wallet, e.g. once the event has changed
+/// hands. Fixed after the first settlement.
+pub fn set_event_treasury(ctx: Context<UpdateEvent>, treasury: Pubkey) -> Result<()> {
+    change_treasury(&mut ctx.accounts.event, treasury)
+}
+
+pub(crate) fn change_treasury(event: &mut Account<Event>, treasury: Pubkey) -> Result<()> {
+    event.guard(EventAction::Treasury)?;
+    require!(treasury != Pubkey::default(), TicketError::InvalidAccount);
+
+    let previous_treasury = event.treasury;
+    event.treasury = treasury;
+    event.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(EventTreasuryChangedEvent {
+        event: event.key(),
+        previous_treasury,
+        treasury,
+        timestamp: event.updated_at,
+    });
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct UpdateEvent<'info> {
     #[account(
         mut,
-        has_one = authority @ TicketError::Unauthorized
+        has_one = authority @ TicketError::Unauthorized,
+        constraint = !event.is_paused(PauseScope::ADMIN) @ TicketError::EventPaused
     )]
     pub event: Account<'info, Event>,
     
     pub authority: Signer<'info>,
-}
\ No newline at end of file
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::ADMIN) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct EventTreasuryChangedEvent {
+    pub event: Pubkey,
+    pub previous_treasury: Pubkey,
+    pub treasury: Pubkey,
+    pub timestamp: i64,
+}
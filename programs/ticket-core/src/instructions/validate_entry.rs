@@ -1,101 +1,163 @@
 use crate::utils::math::*;
-use anchor_lang::prelude::*;
-use crate::state::{Event, Ticket, ValidationRecord, ValidationType};
-use crate::errors::TicketError;
-
-#[derive(Accounts)]
-pub struct ValidateEntry<'info> {
-    #[account(
-        mut,
-        seeds = [b"ticket", event.key().as_ref(), ticket.ticket_id.to_le_bytes().as_ref()],
-        bump,
-        constraint = !ticket.used @ TicketError::TicketAlreadyUsed,
-        constraint = !ticket.is_frozen @ TicketError::TicketFrozen
-    )]
-    pub ticket: Account<'info, Ticket>,
-
-    #[account(
-        seeds = [b"event", event.event_id.to_le_bytes().as_ref()],
-        bump,
-        constraint = !event.cancelled @ TicketError::EventCancelled
-    )]
-    pub event: Account<'info, Event>,
-
-    #[account(
-        init,
-        payer = validator,
-        space = ValidationRecord::LEN,
-        seeds = [b"validation", ticket.key().as_ref(), Clock::get()?.unix_timestamp.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub validation_record: Account<'info, ValidationRecord>,
-
-    /// The gate staff validating the ticket
-    #[account(
-        mut,
-        constraint = event.gate_staff.contains(&validator.key()) || event.organizer == validator.key() @ TicketError::UnauthorizedValidator
-    )]
-    pub validator: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
-pub fn validate_entry(
-    ctx: Context<ValidateEntry>,
-    gate_id: String,
-    validation_type: ValidationType,
-) -> Result<()> {
-    let ticket = &mut ctx.accounts.ticket;
-    let event = &ctx.accounts.event;
-    let validation = &mut ctx.accounts.validation_record;
-    let clock = Clock::get()?;
-
-    // Check event timing
-    require!(
-        clock.unix_timestamp >= event.start_time,
-        TicketError::EventNotStarted
-    );
-    require!(
-        clock.unix_timestamp <= event.end_time + 3600, // Allow 1 hour after end
-        TicketError::EventEnded
-    );
-
-    // Check if ticket has already been validated for entry
-    if validation_type == ValidationType::Entry && ticket.entry_validated {
-        return Err(TicketError::AlreadyValidatedForEntry.into());
-    }
-
-    // Record validation
-    validation.ticket = ticket.key();
-    validation.validator = ctx.accounts.validator.key();
-    validation.timestamp = clock.unix_timestamp;
-    validation.gate_id = gate_id.clone();
-    validation.validation_type = validation_type.clone();
-
-    // Update ticket based on validation type
-    match validation_type {
-        ValidationType::Entry => {
-            ticket.entry_validated = true;
-            ticket.entry_time = Some(clock.unix_timestamp);
-            ticket.entry_gate = Some(gate_id);
-        },
-        ValidationType::Exit => {
-            ticket.exit_time = Some(clock.unix_timestamp);
-            ticket.exit_gate = Some(gate_id);
-        },
-        ValidationType::Checkpoint => {
-            ticket.checkpoint_scans.push((gate_id, clock.unix_timestamp));
-        },
-    }
-
-    ticket.validation_count = safe_add(ticket.validation_count as u64, 1)? as u32;
-    ticket.last_validated = clock.unix_timestamp;
-
-    msg!("Ticket {} validated at gate {} for {:?}", 
-        ticket.ticket_id, 
-        validation.gate_id,
-        validation_type
-    );
-
-    Ok(())
-}
\ No newline at end of file
+use anchor_lang::prelude::*;
+use crate::state::{Event, EventAction, EventRole, EventSeries, Permission, SeriesPass, Ticket, TicketStatus, ValidationRecord, ValidationType};
+use crate::errors::TicketError;
+use crate::state::program_state::ProgramState;
+use crate::traits::{Pausable, PauseScope};
+
+/// Scans either an event ticket or a series pass. `credential` is whichever
+/// of the two is presented; exactly one of `ticket` / `series_pass` is passed.
+#[derive(Accounts)]
+pub struct ValidateEntry<'info> {
+    /// CHECK: Must equal the ticket or series pass below; keys the validation record
+    pub credential: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = credential.key() @ TicketError::InvalidAccount,
+        seeds = [b"ticket", event.key().as_ref(), ticket.ticket_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = !ticket.used @ TicketError::TicketAlreadyUsed,
+        constraint = ticket.status == TicketStatus::Valid @ TicketError::TicketNotValid,
+        constraint = !ticket.is_frozen @ TicketError::TicketFrozen
+    )]
+    pub ticket: Option<Account<'info, Ticket>>,
+
+    #[account(
+        mut,
+        address = credential.key() @ TicketError::InvalidAccount
+    )]
+    pub series_pass: Option<Account<'info, SeriesPass>>,
+
+    /// Required with a series pass
+    pub series: Option<Account<'info, EventSeries>>,
+
+    #[account(
+        seeds = [b"event", event.organizer_account.as_ref(), event.event_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = !event.is_paused(PauseScope::VALIDATE) @ TicketError::EventPaused
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = validator,
+        space = ValidationRecord::LEN,
+        seeds = [b"validation", credential.key().as_ref(), Clock::get()?.unix_timestamp.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub validation_record: Account<'info, ValidationRecord>,
+
+    /// Event organizer or staff with `Permission::VALIDATE`
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    /// The validator's role; omitted when the organizer signs
+    #[account(
+        seeds = [b"role", event.key().as_ref(), validator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, EventRole>>,
+
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused(PauseScope::VALIDATE) @ TicketError::SystemPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn validate_entry(
+    ctx: Context<ValidateEntry>,
+    gate_id: String,
+    validation_type: ValidationType,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let validation = &mut ctx.accounts.validation_record;
+    let clock = Clock::get()?;
+
+    // Gates are open while the event is Live, including the grace period
+    event.guard(EventAction::Validate)?;
+    EventRole::authorize(
+        event,
+        ctx.accounts.validator.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::VALIDATE,
+        Some(&gate_id),
+        ctx.accounts.ticket.as_ref().map(|ticket| ticket.tier_index),
+    )?;
+
+    // Record validation
+    validation.ticket = ctx.accounts.credential.key();
+    validation.validator = ctx.accounts.validator.key();
+    validation.timestamp = clock.unix_timestamp;
+    validation.gate_id = gate_id.clone();
+    validation.validation_type = validation_type.clone();
+
+    match (&mut ctx.accounts.ticket, &mut ctx.accounts.series_pass) {
+        (Some(ticket), None) => {
+            validate_ticket(ticket, gate_id, &validation_type, clock.unix_timestamp)?;
+            msg!("Ticket {} validated at gate {} for {:?}", 
+                ticket.ticket_id, 
+                validation.gate_id,
+                validation_type
+            );
+        },
+        (None, Some(pass)) => {
+            let series = ctx.accounts.series.as_ref().ok_or(TicketError::InvalidAccount)?;
+            require_keys_eq!(pass.series, series.key(), TicketError::InvalidAccount);
+            let event_index = series
+                .event_index(&event.key())
+                .ok_or(TicketError::EventNotInSeries)?;
+
+            // A pass admits once per member event
+            if validation_type == ValidationType::Entry {
+                require!(!pass.has_entered(event_index), TicketError::AlreadyValidated);
+                pass.record_entry(event_index);
+            }
+            msg!("Series pass {} validated at gate {} for {:?}",
+                pass.pass_id,
+                validation.gate_id,
+                validation_type
+            );
+        },
+        _ => return Err(TicketError::InvalidAccount.into()),
+    }
+
+    Ok(())
+}
+
+fn validate_ticket(
+    ticket: &mut Account<Ticket>,
+    gate_id: String,
+    validation_type: &ValidationType,
+    now: i64,
+) -> Result<()> {
+    // Check if ticket has already been validated for entry
+    if *validation_type == ValidationType::Entry && ticket.entry_validated {
+        return Err(TicketError::AlreadyValidatedForEntry.into());
+    }
+
+    // Update ticket based on validation type
+    match validation_type {
+        ValidationType::Entry => {
+            ticket.entry_validated = true;
+            ticket.entry_time = Some(now);
+            ticket.entry_gate = Some(gate_id);
+        },
+        ValidationType::Exit => {
+            ticket.exit_time = Some(now);
+            ticket.exit_gate = Some(gate_id);
+        },
+        ValidationType::Checkpoint => {
+            ticket.checkpoint_scans.push((gate_id, now));
+        },
+    }
+
+    ticket.validation_count = safe_add(ticket.validation_count as u64, 1)? as u32;
+    ticket.last_validated = now;
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ pub mod traits;
 pub mod utils;
 
 use instructions::*;
-use state::{ValidationType, FreezeReason};
+use state::{ValidationType, FreezeReason, MultisigAction, TimelockedAction, RoleConfig, RefundPolicy, TicketTierConfig, TierUpdate, Payee, SeatAssignment, Section};
 
 declare_id!("EeU4nPMu9omn56qNFwWHLBTwPvXQgHNk4E7scsiK8Wwm");
 
@@ -16,15 +16,34 @@ declare_id!("EeU4nPMu9omn56qNFwWHLBTwPvXQgHNk4E7scsiK8Wwm");
 pub mod ticket_core {
     use super::*;
     
+    // Organizer Instructions
+    pub fn register_organizer(
+        ctx: Context<RegisterOrganizer>,
+        default_treasury: Pubkey,
+        default_refund_policy: RefundPolicy,
+    ) -> Result<()> {
+        instructions::organizer::register_organizer(ctx, default_treasury, default_refund_policy)
+    }
+    
+    pub fn update_organizer(
+        ctx: Context<UpdateOrganizer>,
+        default_treasury: Option<Pubkey>,
+        default_refund_policy: Option<RefundPolicy>,
+    ) -> Result<()> {
+        instructions::organizer::update_organizer(ctx, default_treasury, default_refund_policy)
+    }
+    
     // Event Management Instructions
+    #[allow(clippy::too_many_arguments)]
     pub fn create_event(
         ctx: Context<CreateEventCtx>,
         name: String,
         venue: String,
         event_date: i64,
         total_tickets: u32,
-        general_price: u64,
-        vip_price: u64,
+        tiers: Vec<TicketTierConfig>,
+        payees: Vec<Payee>,
+        max_tickets_per_wallet: Option<u16>,
     ) -> Result<()> {
         instructions::create_event::create_event(
             ctx,
@@ -32,8 +51,9 @@ pub mod ticket_core {
             venue,
             event_date,
             total_tickets,
-            general_price,
-            vip_price,
+            tiers,
+            payees,
+            max_tickets_per_wallet,
         )
     }
     
@@ -43,7 +63,7 @@ pub mod ticket_core {
         description: Option<String>,
         venue: Option<String>,
         event_date: Option<i64>,
-        ticket_price: Option<u64>,
+        max_tickets_per_wallet: Option<u16>,
     ) -> Result<()> {
         instructions::update_event::update_event(
             ctx,
@@ -51,49 +71,221 @@ pub mod ticket_core {
             description,
             venue,
             event_date,
-            ticket_price,
+            max_tickets_per_wallet,
         )
     }
     
+    pub fn open_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+        instructions::event_status::open_sales(ctx)
+    }
+
+    pub fn pause_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+        instructions::event_status::pause_sales(ctx)
+    }
+
+    pub fn resume_sales(ctx: Context<SetEventStatus>) -> Result<()> {
+        instructions::event_status::resume_sales(ctx)
+    }
+
+    pub fn start_event(ctx: Context<AdvanceEventStatus>) -> Result<()> {
+        instructions::event_status::start_event(ctx)
+    }
+
+    pub fn end_event(ctx: Context<AdvanceEventStatus>) -> Result<()> {
+        instructions::event_status::end_event(ctx)
+    }
+
+    pub fn pause_event(
+        ctx: Context<PauseEventCtx>,
+        scope: u8,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::pause_event::pause_event(ctx, scope, expires_at)
+    }
+
+    pub fn unpause_event(ctx: Context<PauseEventCtx>, scope: u8) -> Result<()> {
+        instructions::pause_event::unpause_event(ctx, scope)
+    }
+
+    pub fn set_event_treasury(ctx: Context<UpdateEvent>, treasury: Pubkey) -> Result<()> {
+        instructions::update_event::set_event_treasury(ctx, treasury)
+    }
+
+    pub fn propose_event_authority(
+        ctx: Context<ProposeEventAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::event_authority::propose_event_authority(ctx, new_authority)
+    }
+
+    pub fn accept_event_authority(ctx: Context<AcceptEventAuthority>) -> Result<()> {
+        instructions::event_authority::accept_event_authority(ctx)
+    }
+
+    pub fn cancel_event_authority_transfer(ctx: Context<CancelEventAuthorityTransfer>) -> Result<()> {
+        instructions::event_authority::cancel_event_authority_transfer(ctx)
+    }
+
     pub fn cancel_event(ctx: Context<CancelEvent>, reason: String) -> Result<()> {
         instructions::cancel_event::cancel_event(ctx, reason)
     }
     
+    pub fn set_revenue_splits(
+        ctx: Context<SetRevenueSplits>,
+        payees: Vec<Payee>,
+    ) -> Result<()> {
+        instructions::revenue_splits::set_revenue_splits(ctx, payees)
+    }
+    
+    pub fn settle_event<'info>(ctx: Context<'_, '_, 'info, 'info, SettleEvent<'info>>) -> Result<()> {
+        instructions::settle_event::settle_event(ctx)
+    }
+    
+    // Staff Management Instructions
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        wallet: Pubkey,
+        config: RoleConfig,
+    ) -> Result<()> {
+        instructions::manage_staff::grant_role(ctx, wallet, config)
+    }
+    
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::manage_staff::revoke_role(ctx)
+    }
+    
+    // Tier Management Instructions
+    pub fn add_tier(
+        ctx: Context<AddTier>,
+        config: TicketTierConfig,
+    ) -> Result<()> {
+        instructions::manage_tiers::add_tier(ctx, config)
+    }
+    
+    pub fn update_tier(
+        ctx: Context<UpdateTier>,
+        tier_index: u8,
+        update: TierUpdate,
+    ) -> Result<()> {
+        instructions::manage_tiers::update_tier(ctx, tier_index, update)
+    }
+    
+    pub fn close_tier(ctx: Context<CloseTier>, tier_index: u8) -> Result<()> {
+        instructions::manage_tiers::close_tier(ctx, tier_index)
+    }
+    
+    // Seating & Series Instructions
+    pub fn create_seating_map(ctx: Context<CreateSeatingMap>, sections: Vec<Section>) -> Result<()> {
+        instructions::seating::create_seating_map(ctx, sections)
+    }
+    
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: u64,
+        name: String,
+        pass_price: u64,
+        pass_supply: u32,
+    ) -> Result<()> {
+        instructions::series::create_series(ctx, series_id, name, pass_price, pass_supply)
+    }
+    
+    pub fn add_series_event(ctx: Context<AddSeriesEvent>) -> Result<()> {
+        instructions::series::add_series_event(ctx)
+    }
+    
+    pub fn buy_series_pass<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuySeriesPass<'info>>,
+    ) -> Result<()> {
+        instructions::series::buy_series_pass(ctx)
+    }
+    
+    pub fn refund_series_pass(ctx: Context<RefundSeriesPass>) -> Result<()> {
+        instructions::series::refund_series_pass(ctx)
+    }
+    
+    pub fn settle_series<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleSeries<'info>>,
+    ) -> Result<()> {
+        instructions::series::settle_series(ctx)
+    }
+    
     // Ticket Minting Instructions
     pub fn mint_ticket(
         ctx: Context<MintTicketCtx>,
-        tier: String,
-        seat_number: Option<String>,
+        tier_index: u8,
+        seat: Option<SeatAssignment>,
     ) -> Result<()> {
-        instructions::mint_ticket::mint_ticket(ctx, tier, seat_number)
+        instructions::mint_ticket::mint_ticket(ctx, tier_index, seat)
     }
     
-    pub fn batch_mint(
-        ctx: Context<BatchMintCtx>,
-        count: u32,
-        tier: String,
+    pub fn batch_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMintCtx<'info>>,
+        tier_index: u8,
+        quantity: u32,
+        recipients: Vec<Pubkey>,
     ) -> Result<()> {
-        instructions::batch_mint::batch_mint(ctx, count, tier)
+        instructions::batch_mint::batch_mint(ctx, tier_index, quantity, recipients)
     }
     
-    pub fn reserve_tickets(
-        ctx: Context<ReserveTicketsCtx>,
-        count: u32,
+    pub fn reserve_tickets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReserveTicketsCtx<'info>>,
+        tier_index: u8,
+        quantity: u32,
+        recipients: Vec<Pubkey>,
     ) -> Result<()> {
-        instructions::reserve_tickets::reserve_tickets(ctx, count)
+        instructions::reserve_tickets::reserve_tickets(ctx, tier_index, quantity, recipients)
+    }
+    
+    pub fn assign_comp(ctx: Context<AssignCompCtx>, recipient: Pubkey) -> Result<()> {
+        instructions::reserve_tickets::assign_comp(ctx, recipient)
+    }
+    
+    pub fn hold_tickets(ctx: Context<HoldTickets>, tier_index: u8, quantity: u32) -> Result<()> {
+        instructions::holds::hold_tickets(ctx, tier_index, quantity)
+    }
+    
+    pub fn purchase_hold<'info>(ctx: Context<'_, '_, 'info, 'info, PurchaseHold<'info>>) -> Result<()> {
+        instructions::holds::purchase_hold(ctx)
+    }
+    
+    pub fn release_expired_holds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseExpiredHolds<'info>>,
+    ) -> Result<()> {
+        instructions::holds::release_expired_holds(ctx)
     }
     
     pub fn mint_whitelist(
         ctx: Context<MintWhitelistCtx>,
-        tier: String,
-        seat_number: Option<String>,
+        tier_index: u8,
+        allocation: Option<u8>,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        instructions::mint_whitelist::mint_whitelist(ctx, tier, seat_number)
+        instructions::mint_whitelist::mint_whitelist(ctx, tier_index, allocation, proof)
+    }
+    
+    pub fn create_whitelist(
+        ctx: Context<CreateWhitelist>,
+        merkle_root: [u8; 32],
+        max_per_wallet: u8,
+        discount_basis_points: u16,
+    ) -> Result<()> {
+        instructions::manage_whitelist::create_whitelist(ctx, merkle_root, max_per_wallet, discount_basis_points)
+    }
+    
+    pub fn rotate_whitelist_root(ctx: Context<ManageWhitelist>, merkle_root: [u8; 32]) -> Result<()> {
+        instructions::manage_whitelist::rotate_whitelist_root(ctx, merkle_root)
+    }
+    
+    pub fn deactivate_whitelist(ctx: Context<ManageWhitelist>) -> Result<()> {
+        instructions::manage_whitelist::deactivate_whitelist(ctx)
     }
     
     // Transfer Instructions
-    pub fn transfer_ticket(ctx: Context<TransferTicket>) -> Result<()> {
-        instructions::transfer_ticket::transfer_ticket(ctx)
+    pub fn transfer_ticket(
+        ctx: Context<TransferTicket>,
+        transfer_memo: Option<String>,
+    ) -> Result<()> {
+        instructions::transfer_ticket::transfer_ticket(ctx, transfer_memo)
     }
     
     pub fn initialize_delegate_transfer(
@@ -115,9 +307,10 @@ pub mod ticket_core {
     // Validation Instructions
     pub fn validate_entry(
         ctx: Context<ValidateEntry>,
+        gate_id: String,
         validation_type: ValidationType,
     ) -> Result<()> {
-        instructions::validate_entry::validate_entry(ctx, validation_type)
+        instructions::validate_entry::validate_entry(ctx, gate_id, validation_type)
     }
     
     // Ticket Management Instructions
@@ -133,8 +326,9 @@ pub mod ticket_core {
     pub fn freeze_ticket(
         ctx: Context<FreezeTicket>,
         reason: FreezeReason,
+        evidence: String,
     ) -> Result<()> {
-        instructions::freeze_ticket::freeze_ticket(ctx, reason)
+        instructions::freeze_ticket::freeze_ticket(ctx, reason, evidence)
     }
     
     pub fn unfreeze_ticket(
@@ -165,16 +359,36 @@ pub mod ticket_core {
     }
     
     // Admin Instructions
+    pub fn initialize_program(
+        ctx: Context<InitializeProgram>,
+        fee_recipient: Pubkey,
+        platform_fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::admin::initialize_program::initialize_program(ctx, fee_recipient, platform_fee_basis_points)
+    }
+    
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        platform_fee_basis_points: Option<u16>,
+        fee_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::admin::update_config::update_config(ctx, platform_fee_basis_points, fee_recipient)
+    }
+    
     pub fn emergency_pause(
-        ctx: Context<EmergencyPause>
+        ctx: Context<EmergencyPause>,
+        scope: u8,
+        expires_at: Option<i64>,
+        reason: u16,
     ) -> Result<()> {
-        instructions::admin::emergency_pause::emergency_pause(ctx)
+        instructions::admin::emergency_pause::emergency_pause(ctx, scope, expires_at, reason)
     }
     
     pub fn emergency_unpause(
-        ctx: Context<EmergencyUnpause>
+        ctx: Context<EmergencyUnpause>,
+        scope: u8,
     ) -> Result<()> {
-        instructions::admin::emergency_unpause::emergency_unpause(ctx)
+        instructions::admin::emergency_unpause::emergency_unpause(ctx, scope)
     }
     
     pub fn set_compliance(
@@ -183,9 +397,76 @@ pub mod ticket_core {
         instructions::admin::set_compliance::set_compliance(ctx)
     }
     
-    pub fn update_authorities(
-        ctx: Context<UpdateAuthorities>
+    pub fn queue_action(
+        ctx: Context<QueueAction>,
+        action: TimelockedAction,
+    ) -> Result<()> {
+        instructions::admin::timelock::queue_action(ctx, action)
+    }
+    
+    pub fn cancel_queued_action(
+        ctx: Context<CancelQueuedAction>
+    ) -> Result<()> {
+        instructions::admin::timelock::cancel_queued_action(ctx)
+    }
+    
+    pub fn execute_queued_action(
+        ctx: Context<ExecuteQueuedAction>
+    ) -> Result<()> {
+        instructions::admin::timelock::execute_queued_action(ctx)
+    }
+    
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>
+    ) -> Result<()> {
+        instructions::admin::update_authorities::accept_authority(ctx)
+    }
+    
+    pub fn cancel_authority_transfer(
+        ctx: Context<CancelAuthorityTransfer>
+    ) -> Result<()> {
+        instructions::admin::update_authorities::cancel_authority_transfer(ctx)
+    }
+    
+    pub fn withdraw_fees(
+        ctx: Context<WithdrawFees>
+    ) -> Result<()> {
+        instructions::admin::withdraw_fees::withdraw_fees(ctx)
+    }
+    
+    pub fn set_organizer_verified(
+        ctx: Context<SetOrganizerVerified>,
+        verified: bool,
+    ) -> Result<()> {
+        instructions::admin::verify_organizer::set_organizer_verified(ctx, verified)
+    }
+    
+    // Multisig governance
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        multisig_id: u64,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig::create_multisig(ctx, multisig_id, members, threshold)
+    }
+    
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::multisig::create_proposal(ctx, action)
+    }
+    
+    pub fn approve_proposal(
+        ctx: Context<ApproveProposal>
+    ) -> Result<()> {
+        instructions::multisig::approve_proposal(ctx)
+    }
+    
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>
     ) -> Result<()> {
-        instructions::admin::update_authorities::update_authorities(ctx)
+        instructions::multisig::execute_proposal(ctx)
     }
 }
\ No newline at end of file
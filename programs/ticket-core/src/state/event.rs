@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::constants::{MAX_PAYEES, MAX_PRICE_STEPS, MAX_REFUND_TIERS, MAX_TIERS, MAX_TIER_NAME_LEN};
+use crate::errors::TicketError;
+use crate::state::PauseState;
+use crate::traits::Pausable;
+use crate::utils::math::*;
 
 #[account]
+#[derive(Default)]
 pub struct Event {
     // Version control
     pub version: u8,                        // For future upgrades
@@ -15,7 +21,7 @@ pub struct Event {
     pub description: String,
     pub cancelled_at: i64,
     pub cancellation_reason: String,
-    pub refund_enabled: bool,
+    pub refund_enabled: bool,               // Set on cancellation, when every paid ticket is refundable
     pub event_date: i64,                    // Deprecated - use start_time
     pub start_time: i64,
     pub end_time: i64,
@@ -23,29 +29,38 @@ pub struct Event {
     pub tickets_sold: u32,
     pub tickets_used: u32,
     pub tickets_burned: u32,
-    pub general_price: u64,
-    pub vip_price: u64,
-    pub cancelled: bool,
+    pub general_price: u64,                 // Unused since tiers; prices live in TicketTier
+    pub vip_price: u64,                     // Unused since tiers
+    pub cancelled: bool,                    // Deprecated - use status
     pub transferable: bool,
     pub transfer_freeze_time: Option<i64>,
-    pub gate_staff: Vec<Pubkey>,
-    pub freeze_authorities: Vec<Pubkey>,
-    pub burn_authorities: Vec<Pubkey>,
+    pub gate_staff: Vec<Pubkey>,            // Deprecated - use EventRole
+    pub freeze_authorities: Vec<Pubkey>,    // Deprecated - use EventRole
+    pub burn_authorities: Vec<Pubkey>,      // Deprecated - use EventRole
     pub tiers: Vec<TicketTier>,
     pub bump: u8,
     
     // New fields for 10/10
     pub refund_policy: RefundPolicy,        // Refund configuration
     pub compliance_rules: Option<Pubkey>,   // Link to compliance config
-    pub is_paused: bool,                    // Circuit breaker
+    pub pause_state: PauseState,            // Circuit breaker, see Pausable
     pub pause_authority: Option<Pubkey>,    // Who can pause
-    pub price_multiplier: u16,              // Surge pricing (100 = 1x, 200 = 2x)
-    pub last_price_update: i64,             // When price last changed
+    pub price_multiplier: u16,              // Last multiplier applied on any tier (100 = 1x, 200 = 2x)
+    pub last_price_update: i64,             // When any tier's price last changed
     pub insurance_pool: Option<Pubkey>,     // Link to insurance
     pub analytics_enabled: bool,            // Track detailed analytics
     pub max_tickets_per_wallet: Option<u16>, // Anti-scalping
     pub created_at: i64,                    // Creation timestamp
     pub updated_at: i64,                    // Last update timestamp
+    pub next_ticket_id: u64,                // Sequential ticket numbering
+    pub payees: Vec<Payee>,                 // Revenue split, empty = all to treasury
+    pub comps_issued: u32,                  // Zero-price reservations, not in tickets_sold
+    pub seating_map: Option<Pubkey>,        // Set once reserved seating is configured
+    pub series: Option<Pubkey>,             // EventSeries this event belongs to
+    pub series_reserved: u32,               // Capacity set aside for series passes
+    pub status: EventStatus,                // Lifecycle, see Event::guard
+    pub pending_authority: Option<Pubkey>,  // Proposed new organizer, not yet accepted
+    pub organizer_account: Pubkey,          // Organizer PDA that numbered the event; a PDA seed only, kept on handover
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -60,6 +75,209 @@ pub struct TicketTier {
     pub dynamic_pricing_enabled: bool,       // NEW
     pub min_price: Option<u64>,             // NEW
     pub max_price: Option<u64>,             // NEW
+    pub status: TierStatus,                 // Sale availability
+    pub pricing_curve: PricingCurve,        // Used when dynamic_pricing_enabled
+    pub held_count: u32,                    // In open checkout holds
+    pub seated: bool,                       // Reserved seating, see SeatingMap
+    pub price_multiplier: u16,              // Last dynamic multiplier applied (100 = 1x)
+    pub last_price_update: i64,             // When price_multiplier last changed
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TierStatus {
+    #[default]
+    Active,
+    Paused,
+    Closed,
+}
+
+/// Event lifecycle. Organizers move an event through the sales states; the
+/// Live and Ended transitions are permissionless once the clock allows them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EventStatus {
+    #[default]
+    Draft,
+    OnSale,
+    SalesPaused,
+    Live,
+    Ended,
+    Cancelled,
+    Settled,
+}
+
+/// What an instruction does to an event, checked against its status by
+/// `Event::guard`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EventAction {
+    Configure,  // Details, tiers, whitelist, seating, series, revenue splits
+    Sell,       // Paid mints and checkout holds
+    Comp,       // Issuing and assigning comps
+    Transfer,   // Owner and delegated transfers
+    Refund,     // Buyer refund requests
+    PayRefund,  // Paying out pending refund requests
+    Freeze,     // Freezing and unfreezing tickets
+    Validate,   // Gate scans
+    Redeem,     // Marking used and burning validated tickets
+    Settle,     // Releasing escrowed revenue
+    Staff,      // Granting staff roles
+    Treasury,   // Changing where settlement pays out
+}
+
+impl EventStatus {
+    pub fn allows(self, action: EventAction) -> bool {
+        use EventStatus::*;
+        match action {
+            EventAction::Configure => matches!(self, Draft | OnSale | SalesPaused),
+            EventAction::Sell => matches!(self, OnSale | Live),
+            EventAction::Comp | EventAction::Transfer => {
+                matches!(self, Draft | OnSale | SalesPaused | Live)
+            },
+            EventAction::Refund => matches!(self, OnSale | SalesPaused | Cancelled),
+            // Requests made before the event went live must still be payable
+            EventAction::PayRefund => self != Draft,
+            EventAction::Freeze => matches!(self, Draft | OnSale | SalesPaused | Live | Ended),
+            EventAction::Validate => self == Live,
+            EventAction::Redeem => matches!(self, Live | Ended),
+            EventAction::Settle => matches!(self, Ended | Settled),
+            EventAction::Staff => matches!(self, Draft | OnSale | SalesPaused | Live | Ended),
+            // Fixed from the first payout so every settlement goes to one place
+            EventAction::Treasury => matches!(self, Draft | OnSale | SalesPaused | Live | Ended),
+        }
+    }
+
+    pub fn can_transition_to(self, next: EventStatus) -> bool {
+        use EventStatus::*;
+        matches!(
+            (self, next),
+            (Draft, OnSale)
+                | (OnSale, SalesPaused)
+                | (SalesPaused, OnSale)
+                | (OnSale | SalesPaused, Live)
+                | (Live, Ended)
+                | (Ended, Settled)
+                | (Draft | OnSale | SalesPaused, Cancelled)
+        )
+    }
+}
+
+/// How a dynamic tier's price scales with utilization. All values are basis
+/// points: utilization 10,000 = sold out, multiplier 10,000 = base price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PricingCurve {
+    /// Jumps to `multiplier_bps` once utilization reaches each threshold.
+    StepTable { steps: Vec<PriceStep> },
+    /// Rises evenly from 1x to `max_multiplier_bps` at sell-out.
+    Linear { max_multiplier_bps: u32 },
+    /// Grows by `growth_bps` for every `step_bps` of utilization.
+    Exponential { growth_bps: u32, step_bps: u16 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PriceStep {
+    pub utilization_bps: u16,
+    pub multiplier_bps: u32,
+}
+
+impl Default for PricingCurve {
+    fn default() -> Self {
+        PricingCurve::StepTable {
+            steps: vec![
+                PriceStep { utilization_bps: 5_000, multiplier_bps: 11_000 },  // 50%: 1.1x
+                PriceStep { utilization_bps: 7_000, multiplier_bps: 12_500 },  // 70%: 1.25x
+                PriceStep { utilization_bps: 8_000, multiplier_bps: 15_000 },  // 80%: 1.5x
+                PriceStep { utilization_bps: 9_000, multiplier_bps: 20_000 },  // 90%: 2x
+            ]
+        }
+    }
+}
+
+impl PricingCurve {
+    pub const LEN: usize = 1 + 4 + (PriceStep::LEN * MAX_PRICE_STEPS); // largest variant
+
+    pub fn multiplier_bps(&self, sold: u32, capacity: u32) -> Result<u32> {
+        curve_multiplier_bps(self, utilization_bps(sold, capacity))
+    }
+}
+
+impl PriceStep {
+    pub const LEN: usize = 2 + 4;
+}
+
+/// Tier definition supplied by the organizer; counters start at zero.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TicketTierConfig {
+    pub name: String,
+    pub price: u64,
+    pub total_supply: u32,
+    pub dynamic_pricing_enabled: bool,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+    pub pricing_curve: Option<PricingCurve>, // None = default step table
+}
+
+/// Changes to an existing tier; `None` leaves a field as it is.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TierUpdate {
+    pub price: Option<u64>,
+    pub total_supply: Option<u32>,
+    pub dynamic_pricing_enabled: Option<bool>,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+    pub pricing_curve: Option<PricingCurve>,
+    pub paused: Option<bool>,
+}
+
+impl From<TicketTierConfig> for TicketTier {
+    fn from(config: TicketTierConfig) -> Self {
+        TicketTier {
+            name: config.name,
+            price: config.price,
+            total_supply: config.total_supply,
+            dynamic_pricing_enabled: config.dynamic_pricing_enabled,
+            min_price: config.min_price,
+            max_price: config.max_price,
+            pricing_curve: config.pricing_curve.unwrap_or_default(),
+            price_multiplier: 100,
+            ..Default::default()
+        }
+    }
+}
+
+impl TicketTier {
+    pub const LEN: usize = 4 + MAX_TIER_NAME_LEN + // name
+        8 + // price
+        4 + // total_supply
+        4 + // minted_count
+        4 + // used_count
+        4 + // burned_count
+        4 + // refunded_count
+        1 + // dynamic_pricing_enabled
+        9 + // min_price
+        9 + // max_price
+        1 + // status
+        PricingCurve::LEN + // pricing_curve
+        4 + // held_count
+        1 + // seated
+        2 + // price_multiplier
+        8; // last_price_update
+
+    /// Supply still available to new buyers, excluding held tickets.
+    pub fn remaining(&self) -> u32 {
+        self.total_supply
+            .saturating_sub(self.minted_count)
+            .saturating_sub(self.held_count)
+    }
+}
+
+/// Share of settled revenue owed to one party (promoter, venue, artist...).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Payee {
+    pub wallet: Pubkey,
+    pub basis_points: u16,                  // Shares sum to 10,000
+}
+
+impl Payee {
+    pub const LEN: usize = 32 + 2;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -83,6 +301,10 @@ pub struct RefundTier {
     pub fee_basis_points: u16,  // Platform keeps this fee
 }
 
+impl RefundTier {
+    pub const LEN: usize = 4 + 1 + 2;
+}
+
 impl Default for RefundPolicy {
     fn default() -> Self {
         RefundPolicy::TieredRefund {
@@ -95,6 +317,48 @@ impl Default for RefundPolicy {
     }
 }
 
+impl RefundPolicy {
+    pub const LEN: usize = 1 + 4 + (RefundTier::LEN * MAX_REFUND_TIERS); // variant + largest payload (TieredRefund)
+
+    /// Rejects policies that don't fit `LEN` or pay out more than the ticket.
+    pub fn validate(&self) -> Result<()> {
+        if let RefundPolicy::TieredRefund { tiers } = self {
+            require!(tiers.len() <= MAX_REFUND_TIERS, TicketError::InvalidRefundPolicy);
+            for tier in tiers {
+                require!(
+                    tier.refund_percentage <= 100 && tier.fee_basis_points <= 10_000,
+                    TicketError::InvalidRefundPolicy
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn allows_refunds(&self) -> bool {
+        match self {
+            RefundPolicy::NoRefunds => false,
+            RefundPolicy::TieredRefund { tiers } => tiers.iter().any(|t| t.refund_percentage > 0),
+            _ => true,
+        }
+    }
+
+    /// Last moment a refund can be requested, if the policy defines one.
+    pub fn refund_deadline(&self, event_start: i64) -> Option<i64> {
+        match self {
+            RefundPolicy::FullRefund { hours_before_event } => {
+                Some(event_start - *hours_before_event as i64 * 3600)
+            },
+            RefundPolicy::TieredRefund { tiers } => tiers
+                .iter()
+                .filter(|t| t.refund_percentage > 0)
+                .map(|t| t.hours_before)
+                .min()
+                .map(|hours| event_start - hours as i64 * 3600),
+            _ => None,
+        }
+    }
+}
+
 impl Event {
     pub const LEN: usize = 8 + // discriminator
         1 + // version
@@ -115,14 +379,14 @@ impl Event {
         1 + // cancelled
         1 + // transferable
         9 + // transfer_freeze_time
-        (32 * 10) + // gate_staff
-        (32 * 10) + // freeze_authorities
-        (32 * 10) + // burn_authorities
-        (200 * 5) + // tiers
+        4 + // gate_staff (deprecated, always empty)
+        4 + // freeze_authorities (deprecated, always empty)
+        4 + // burn_authorities (deprecated, always empty)
+        4 + (TicketTier::LEN * MAX_TIERS) + // tiers
         1 + // bump
-        64 + // refund_policy
+        RefundPolicy::LEN + // refund_policy
         33 + // compliance_rules
-        1 + // is_paused
+        PauseState::LEN + // pause_state
         33 + // pause_authority
         2 + // price_multiplier
         8 + // last_price_update
@@ -131,5 +395,404 @@ impl Event {
         3 + // max_tickets_per_wallet
         8 + // created_at
         8 + // updated_at
+        8 + // next_ticket_id
+        4 + (Payee::LEN * MAX_PAYEES) + // payees
+        4 + // comps_issued
+        33 + // seating_map
+        33 + // series
+        4 + // series_reserved
+        1 + // status
+        33 + // pending_authority
+        32 + // organizer_account
         256; // padding
+
+    /// Fails unless the event's status allows `action`. Every instruction
+    /// that touches an event goes through here instead of checking flags
+    /// and timestamps itself.
+    pub fn guard(&self, action: EventAction) -> Result<()> {
+        if self.status.allows(action) {
+            return Ok(());
+        }
+        match self.status {
+            EventStatus::Cancelled => Err(TicketError::EventCancelled.into()),
+            _ => Err(TicketError::InvalidEventStatus.into()),
+        }
+    }
+
+    /// Moves the event to `next`, returning the status it left.
+    pub fn transition(&mut self, next: EventStatus, now: i64) -> Result<EventStatus> {
+        require!(
+            self.status.can_transition_to(next),
+            TicketError::InvalidStatusTransition
+        );
+        let previous = self.status;
+        self.status = next;
+        self.updated_at = now;
+        Ok(previous)
+    }
+
+    /// Who can pause the event: its pause authority, or the organizer's
+    /// authority if none was set.
+    pub fn pause_authority(&self) -> Pubkey {
+        self.pause_authority.unwrap_or(self.authority)
+    }
+
+    /// When the event is over: its end time, or its start if none was set.
+    pub fn ends_at(&self) -> i64 {
+        self.start_time.max(self.end_time)
+    }
+
+    /// Once anything has sold, the revenue split is locked. Comps don't count.
+    pub fn sales_started(&self) -> bool {
+        self.next_ticket_id > self.comps_issued as u64 || self.tickets_sold > 0
+    }
+
+    pub fn tier(&self, tier_index: usize) -> Result<&TicketTier> {
+        self.tiers
+            .get(tier_index)
+            .ok_or_else(|| TicketError::InvalidTier.into())
+    }
+
+    /// Seated tiers can only be sold through `mint_ticket`, which claims a seat.
+    pub fn require_unseated(&self, tier_index: usize) -> Result<()> {
+        require!(!self.tier(tier_index)?.seated, TicketError::SeatRequired);
+        Ok(())
+    }
+
+    /// Price to charge for the next ticket in a tier. Dynamic tiers are priced
+    /// from utilization and clamped to the tier's bounds; when the tier's
+    /// multiplier moves it is recorded on the tier and emitted.
+    pub fn mint_price(&mut self, event_key: Pubkey, tier_index: usize, now: i64) -> Result<u64> {
+        let tier = self.tier(tier_index)?;
+        if !tier.dynamic_pricing_enabled {
+            return Ok(tier.price);
+        }
+
+        let multiplier_bps = tier
+            .pricing_curve
+            .multiplier_bps(tier.minted_count, tier.total_supply)?;
+        let mut price = apply_multiplier_bps(tier.price, multiplier_bps)?;
+        if let Some(min_price) = tier.min_price {
+            price = price.max(min_price);
+        }
+        if let Some(max_price) = tier.max_price {
+            price = price.min(max_price);
+        }
+
+        // The event-level multiplier keeps its percent scale (100 = 1x)
+        let multiplier = u16::try_from(multiplier_bps / 100)
+            .map_err(|_| TicketError::ArithmeticOverflow)?;
+        if multiplier != tier.price_multiplier {
+            emit!(PriceUpdatedEvent {
+                event: event_key,
+                tier_index: tier_index as u8,
+                base_price: tier.price,
+                previous_multiplier: tier.price_multiplier,
+                multiplier,
+                price,
+                minted_count: tier.minted_count,
+                total_supply: tier.total_supply,
+                timestamp: now,
+            });
+            let tier = &mut self.tiers[tier_index];
+            tier.price_multiplier = multiplier;
+            tier.last_price_update = now;
+            self.price_multiplier = multiplier;
+            self.last_price_update = now;
+        }
+
+        Ok(price)
+    }
+
+    /// Reserves `quantity` tickets against both the tier and the event capacity.
+    pub fn record_mint(&mut self, tier_index: usize, quantity: u32) -> Result<()> {
+        self.check_capacity(quantity)?;
+
+        let tier = self
+            .tiers
+            .get_mut(tier_index)
+            .ok_or(TicketError::InvalidTier)?;
+        require!(tier.status == TierStatus::Active, TicketError::TierNotOnSale);
+        require!(quantity <= tier.remaining(), TicketError::TierSoldOut);
+
+        tier.minted_count += quantity;
+        self.tickets_sold += quantity;
+        Ok(())
+    }
+
+    /// Like `record_mint` for comps: they use up tier and event capacity but
+    /// are counted in `comps_issued` rather than `tickets_sold`. Paused tiers
+    /// can still be comped.
+    pub fn record_comps(&mut self, tier_index: usize, quantity: u32) -> Result<()> {
+        self.check_capacity(quantity)?;
+
+        let tier = self
+            .tiers
+            .get_mut(tier_index)
+            .ok_or(TicketError::InvalidTier)?;
+        require!(tier.status != TierStatus::Closed, TicketError::TierClosed);
+        require!(quantity <= tier.remaining(), TicketError::TierSoldOut);
+
+        tier.minted_count += quantity;
+        self.comps_issued += quantity;
+        Ok(())
+    }
+
+    /// What a wallet that has bought `purchased` tickets would hold after
+    /// `quantity` more, failing past `max_tickets_per_wallet`.
+    pub fn wallet_total_after(&self, purchased: u32, quantity: u32) -> Result<u32> {
+        let total = purchased
+            .checked_add(quantity)
+            .ok_or(TicketError::ArithmeticOverflow)?;
+        if let Some(max) = self.max_tickets_per_wallet {
+            require!(total <= max as u32, TicketError::MaximumTicketsExceeded);
+        }
+        Ok(total)
+    }
+
+    /// Capacity not yet sold, comped, held or set aside for series passes.
+    pub fn available_capacity(&self) -> u32 {
+        self.total_tickets
+            .saturating_sub(self.tickets_sold)
+            .saturating_sub(self.comps_issued)
+            .saturating_sub(self.held_count())
+            .saturating_sub(self.series_reserved)
+    }
+
+    /// Tickets in open checkout holds across all tiers.
+    pub fn held_count(&self) -> u32 {
+        self.tiers
+            .iter()
+            .fold(0u32, |held, tier| held.saturating_add(tier.held_count))
+    }
+
+    fn check_capacity(&self, quantity: u32) -> Result<()> {
+        require!(quantity <= self.available_capacity(), TicketError::EventFull);
+        Ok(())
+    }
+
+    /// Sets aside `quantity` tickets of an on-sale tier for a checkout hold,
+    /// against both the tier and the event capacity.
+    pub fn hold_tickets(&mut self, tier_index: usize, quantity: u32) -> Result<()> {
+        self.check_capacity(quantity)?;
+
+        let tier = self
+            .tiers
+            .get_mut(tier_index)
+            .ok_or(TicketError::InvalidTier)?;
+        require!(tier.status == TierStatus::Active, TicketError::TierNotOnSale);
+        require!(quantity <= tier.remaining(), TicketError::TierSoldOut);
+
+        tier.held_count += quantity;
+        Ok(())
+    }
+
+    /// Returns held tickets to the tier's available supply.
+    pub fn release_hold(&mut self, tier_index: usize, quantity: u32) {
+        if let Some(tier) = self.tiers.get_mut(tier_index) {
+            tier.held_count = tier.held_count.saturating_sub(quantity);
+        }
+    }
+}
+
+impl Pausable for Event {
+    fn pause(&mut self, scope: u8, expires_at: Option<i64>) -> Result<()> {
+        self.pause_state.pause(scope, expires_at)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    fn unpause(&mut self, scope: u8) -> Result<()> {
+        self.pause_state.unpause(scope)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    fn paused_scopes(&self, now: i64) -> u8 {
+        self.pause_state.paused_scopes(now)
+    }
+}
+
+#[event]
+pub struct PriceUpdatedEvent {
+    pub event: Pubkey,
+    pub tier_index: u8,
+    pub base_price: u64,
+    pub previous_multiplier: u16,
+    pub multiplier: u16,                    // 100 = 1x
+    pub price: u64,
+    pub minted_count: u32,
+    pub total_supply: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EventStatusChangedEvent {
+    pub event: Pubkey,
+    pub previous: EventStatus,
+    pub status: EventStatus,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(name: &str, total_supply: u32, curve: PricingCurve) -> TicketTier {
+        TicketTier::from(TicketTierConfig {
+            name: name.to_string(),
+            price: 1_000,
+            total_supply,
+            dynamic_pricing_enabled: true,
+            min_price: None,
+            max_price: None,
+            pricing_curve: Some(curve),
+        })
+    }
+
+    fn event(tiers: Vec<TicketTier>) -> Event {
+        Event {
+            total_tickets: tiers.iter().map(|t| t.total_supply).sum(),
+            tiers,
+            price_multiplier: 100,
+            status: EventStatus::OnSale,
+            ..Default::default()
+        }
+    }
+
+    const ALL_STATUSES: [EventStatus; 7] = [
+        EventStatus::Draft,
+        EventStatus::OnSale,
+        EventStatus::SalesPaused,
+        EventStatus::Live,
+        EventStatus::Ended,
+        EventStatus::Cancelled,
+        EventStatus::Settled,
+    ];
+
+    #[test]
+    fn only_listed_transitions_are_allowed() {
+        use EventStatus::*;
+        let allowed = [
+            (Draft, OnSale),
+            (OnSale, SalesPaused),
+            (SalesPaused, OnSale),
+            (OnSale, Live),
+            (SalesPaused, Live),
+            (Live, Ended),
+            (Ended, Settled),
+            (Draft, Cancelled),
+            (OnSale, Cancelled),
+            (SalesPaused, Cancelled),
+        ];
+        for from in ALL_STATUSES {
+            for to in ALL_STATUSES {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{:?} -> {:?}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn guard_follows_the_status_table() {
+        let mut event = event(vec![tier("GA", 100, PricingCurve::default())]);
+
+        event.status = EventStatus::Draft;
+        assert!(event.guard(EventAction::Configure).is_ok());
+        assert_eq!(event.guard(EventAction::Sell).unwrap_err(), TicketError::InvalidEventStatus.into());
+        assert!(event.guard(EventAction::PayRefund).is_err());
+
+        event.status = EventStatus::Live;
+        assert!(event.guard(EventAction::Sell).is_ok());
+        assert!(event.guard(EventAction::Validate).is_ok());
+        assert!(event.guard(EventAction::PayRefund).is_ok());
+        assert!(event.guard(EventAction::Configure).is_err());
+        assert!(event.guard(EventAction::Refund).is_err());
+
+        event.status = EventStatus::Ended;
+        assert!(event.guard(EventAction::Redeem).is_ok());
+        assert!(event.guard(EventAction::Settle).is_ok());
+        assert!(event.guard(EventAction::Treasury).is_ok());
+        assert!(event.guard(EventAction::Validate).is_err());
+
+        event.status = EventStatus::Settled;
+        assert!(event.guard(EventAction::Settle).is_ok());
+        assert!(event.guard(EventAction::Treasury).is_err());
+
+        // A cancelled event only refunds, and says why everything else fails
+        event.status = EventStatus::Cancelled;
+        assert!(event.guard(EventAction::Refund).is_ok());
+        assert!(event.guard(EventAction::PayRefund).is_ok());
+        for action in [EventAction::Sell, EventAction::Transfer, EventAction::Validate, EventAction::Settle] {
+            assert_eq!(event.guard(action).unwrap_err(), TicketError::EventCancelled.into());
+        }
+    }
+
+    #[test]
+    fn dynamic_tiers_track_their_own_multiplier() {
+        let mut event = event(vec![
+            tier("GA", 100, PricingCurve::Linear { max_multiplier_bps: 30_000 }),
+            tier("VIP", 100, PricingCurve::default()),
+        ]);
+        event.tiers[0].minted_count = 50;
+        let key = Pubkey::new_unique();
+
+        // GA moves to 2x, VIP stays at 1x
+        event.mint_price(key, 0, 1).unwrap();
+        assert_eq!(event.tiers[0].price_multiplier, 200);
+        assert_eq!(event.tiers[0].last_price_update, 1);
+
+        event.mint_price(key, 1, 2).unwrap();
+        assert_eq!(event.tiers[1].price_multiplier, 100);
+        assert_eq!(event.tiers[1].last_price_update, 0);
+
+        // Selling GA again at the same utilization is not a price change
+        event.mint_price(key, 0, 3).unwrap();
+        assert_eq!(event.tiers[0].last_price_update, 1);
+    }
+
+    #[test]
+    fn sold_and_held_tickets_fill_capacity() {
+        let mut event = event(vec![
+            tier("GA", 60, PricingCurve::default()),
+            tier("VIP", 40, PricingCurve::default()),
+        ]);
+        event.series_reserved = 40;
+
+        event.record_mint(0, 30).unwrap();
+        event.hold_tickets(1, 30).unwrap();
+        assert_eq!(event.held_count(), 30);
+        assert_eq!(event.available_capacity(), 0);
+
+        // Both tiers still have supply, but the event has none left
+        assert_eq!(event.hold_tickets(0, 1).unwrap_err(), TicketError::EventFull.into());
+        assert_eq!(event.record_mint(0, 1).unwrap_err(), TicketError::EventFull.into());
+
+        // An expired hold gives the capacity back
+        event.release_hold(1, 30);
+        event.hold_tickets(0, 1).unwrap();
+    }
+
+    #[test]
+    fn purchase_past_wallet_cap_fails() {
+        let mut event = event(vec![tier("GA", 100, PricingCurve::default())]);
+        assert_eq!(event.wallet_total_after(50, 50).unwrap(), 100);
+
+        event.max_tickets_per_wallet = Some(4);
+        let purchased = event.wallet_total_after(0, 3).unwrap();
+        assert_eq!(event.wallet_total_after(purchased, 1).unwrap(), 4);
+        assert_eq!(
+            event.wallet_total_after(purchased, 2).unwrap_err(),
+            TicketError::MaximumTicketsExceeded.into()
+        );
+        assert_eq!(
+            event.wallet_total_after(4, 1).unwrap_err(),
+            TicketError::MaximumTicketsExceeded.into()
+        );
+    }
 }
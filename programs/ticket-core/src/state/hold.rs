@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Tickets set aside for one buyer while they check out. Held tickets count
+/// against tier supply until the hold is purchased or released.
+#[account]
+pub struct TicketHold {
+    pub event: Pubkey,
+    pub buyer: Pubkey,
+    pub tier_index: u8,
+    pub quantity: u32,
+    pub price: u64,                         // Per ticket, locked when the hold was placed
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl TicketHold {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        32 + // buyer
+        1 + // tier_index
+        4 + // quantity
+        8 + // price
+        8 + // created_at
+        8 + // expires_at
+        1; // bump
+}
@@ -5,12 +5,34 @@ pub mod refund;
 pub mod compliance;
 pub mod analytics;
 pub mod insurance;
+pub mod vault;
+pub mod purchase;
+pub mod hold;
+pub mod seating;
+pub mod series;
+pub mod whitelist;
+pub mod pause;
+pub mod multisig;
+pub mod timelock;
+pub mod role;
+pub mod organizer;
 
 pub use event::*;
 pub use ticket::*;
 pub use compliance::*;
 pub use analytics::*;
 pub use insurance::*;
+pub use vault::*;
+pub use purchase::*;
+pub use hold::*;
+pub use seating::*;
+pub use series::*;
+pub use whitelist::*;
+pub use pause::*;
+pub use multisig::*;
+pub use timelock::*;
+pub use role::*;
+pub use organizer::*;
 
 // Legacy types - these were in the original state.rs
 use anchor_lang::prelude::*;
@@ -77,18 +99,6 @@ pub enum FreezeReason {
     Other,
 }
 
-#[account]
-pub struct Whitelist {
-    pub event: Pubkey,
-    pub merkle_root: [u8; 32],
-    pub max_per_wallet: u8,
-    pub active: bool,
-}
-
-impl Whitelist {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 64;
-}
-
 // Re-export from admin module
 pub use self::refund::{RefundRequest, RefundStatus};
 // Event emissions
@@ -101,5 +111,8 @@ pub struct EventUpdatedEvent {
 #[event]
 pub struct EventCancelledEvent {
     pub event_id: Pubkey,
-    pub cancelled_at: i64,
+    pub authority: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+    pub refund_enabled: bool,
 }
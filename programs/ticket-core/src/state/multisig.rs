@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_CANCELLATION_REASON_LEN, MAX_MULTISIG_MEMBERS};
+use crate::errors::TicketError;
+use crate::state::{RoleConfig, TimelockedAction};
+
+/// Signer set that can stand in for a single key as `ProgramState.authority`
+/// or an event's authority. Members and threshold are fixed at creation; to
+/// rotate, create a new multisig and hand the authority over to it.
+#[account]
+pub struct Multisig {
+    pub creator: Pubkey,
+    pub multisig_id: u64,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,                      // Approvals needed to execute
+    pub proposal_count: u64,                // Next proposal id
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // multisig_id
+        4 + (32 * MAX_MULTISIG_MEMBERS) + // members
+        1 + // threshold
+        8 + // proposal_count
+        8 + // created_at
+        1; // bump
+
+    /// Members must be distinct real keys, and the threshold reachable.
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_MULTISIG_MEMBERS,
+            TicketError::InvalidMultisigConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            TicketError::InvalidMultisigConfig
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(*member != Pubkey::default(), TicketError::InvalidMultisigConfig);
+            require!(!members[..i].contains(member), TicketError::DuplicateSigner);
+        }
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+/// An action waiting for approvals. Executing it applies the same state
+/// change as the matching single-key instruction.
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+    pub created_at: i64,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        8 + // proposal_id
+        32 + // proposer
+        MultisigAction::LEN + // action
+        4 + (32 * MAX_MULTISIG_MEMBERS) + // approvals
+        1 + // executed
+        8 + // created_at
+        9 + // executed_at
+        1; // bump
+
+    pub fn approve(&mut self, member: Pubkey) -> Result<()> {
+        require!(!self.executed, TicketError::ProposalAlreadyExecuted);
+        require!(!self.approvals.contains(&member), TicketError::DuplicateSigner);
+        self.approvals.push(member);
+        Ok(())
+    }
+}
+
+/// Sensitive actions a multisig can take. Program-level actions require the
+/// multisig to be `ProgramState.authority` and are subject to the same
+/// timelock as a single key; event-level ones require it to be
+/// that event's authority (or pause authority, for pauses). The `Accept*`
+/// variants complete a handover *to* the multisig.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum MultisigAction {
+    Pause { scope: u8, expires_at: Option<i64>, reason: u16 },
+    Unpause { scope: u8 },
+    UpdateConfig { platform_fee_basis_points: Option<u16>, fee_recipient: Option<Pubkey> },
+    QueueAction { action: TimelockedAction },
+    CancelQueuedAction { queued_action: Pubkey },
+    AcceptAuthority,
+    WithdrawFees,
+    PauseEvent { event: Pubkey, scope: u8, expires_at: Option<i64> },
+    UnpauseEvent { event: Pubkey, scope: u8 },
+    ProposeEventAuthority { event: Pubkey, new_authority: Pubkey },
+    AcceptEventAuthority { event: Pubkey },
+    SettleEvent { event: Pubkey },
+    OpenSales { event: Pubkey },
+    PauseSales { event: Pubkey },
+    ResumeSales { event: Pubkey },
+    CancelEvent { event: Pubkey, reason: String },
+    ProcessRefund { event: Pubkey, ticket: Pubkey },
+    GrantRole { event: Pubkey, wallet: Pubkey, config: RoleConfig },
+    RevokeRole { event: Pubkey, wallet: Pubkey },
+    SetEventTreasury { event: Pubkey, treasury: Pubkey },
+}
+
+impl MultisigAction {
+    pub const LEN: usize = 1 + 32 + 4 + MAX_CANCELLATION_REASON_LEN; // variant + largest payload (CancelEvent)
+
+    /// Bounds the variable-length payloads so the action fits the proposal.
+    /// Everything else is checked on execution, as the single-key
+    /// instruction would check it.
+    pub fn validate(&self, now: i64) -> Result<()> {
+        match self {
+            Self::CancelEvent { reason, .. } => require!(
+                reason.len() <= MAX_CANCELLATION_REASON_LEN,
+                TicketError::CancellationReasonTooLong
+            ),
+            Self::GrantRole { config, .. } => config.validate(now)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// The event this action targets, if it is event-level.
+    pub fn event(&self) -> Option<Pubkey> {
+        match self {
+            Self::PauseEvent { event, .. }
+            | Self::UnpauseEvent { event, .. }
+            | Self::ProposeEventAuthority { event, .. }
+            | Self::AcceptEventAuthority { event }
+            | Self::SettleEvent { event }
+            | Self::OpenSales { event }
+            | Self::PauseSales { event }
+            | Self::ResumeSales { event }
+            | Self::CancelEvent { event, .. }
+            | Self::ProcessRefund { event, .. }
+            | Self::GrantRole { event, .. }
+            | Self::RevokeRole { event, .. }
+            | Self::SetEventTreasury { event, .. } => Some(*event),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::RefundPolicy;
+
+/// One per organizer wallet, at PDA `[b"organizer", authority]`. Numbers the
+/// wallet's events and supplies their defaults.
+#[account]
+pub struct Organizer {
+    pub authority: Pubkey,
+    pub event_count: u64,                   // Next event_id
+    pub default_treasury: Pubkey,           // New events' treasury
+    pub default_refund_policy: RefundPolicy, // New events' refund policy
+    pub verified: bool,                     // Set by the program authority
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Organizer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        8 + // event_count
+        32 + // default_treasury
+        RefundPolicy::LEN + // default_refund_policy
+        1 + // verified
+        8 + // created_at
+        1 + // bump
+        64; // padding
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::traits::{Pausable, PauseScope};
+
+/// Scoped pause shared by `ProgramState` and `Event`. Each scope expires on
+/// its own, so a short TRANSFER pause does not lift a longer MINT one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct PauseState {
+    pub scopes: u8,                         // PauseScope bits
+    pub paused_at: i64,
+    pub expires_at: [Option<i64>; 5],       // Indexed by PauseScope bit; None = until unpaused
+}
+
+impl PauseState {
+    pub const LEN: usize = 1 + 8 + 9 * 5;
+
+    /// [`Pausable::pause`] against an explicit timestamp.
+    fn pause_at(&mut self, scope: u8, expires_at: Option<i64>, now: i64) -> Result<()> {
+        require!(
+            scope != 0 && scope & !PauseScope::ALL == 0,
+            TicketError::InvalidPauseScope
+        );
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, TicketError::InvalidPauseExpiry);
+        }
+
+        let active = self.paused_scopes(now);
+        require!(active & scope != scope, TicketError::AlreadyPaused);
+
+        if active == 0 {
+            self.paused_at = now;
+        }
+        for (i, expiry) in self.expires_at.iter_mut().enumerate() {
+            let bit = 1 << i;
+            *expiry = match (active & bit != 0, scope & bit != 0) {
+                // Never shortens a running pause: the later expiry wins, open-ended beats timed
+                (true, true) => expiry.zip(expires_at).map(|(a, b)| a.max(b)),
+                (true, false) => *expiry,
+                (false, true) => expires_at,
+                (false, false) => None,
+            };
+        }
+        self.scopes = active | scope;
+        Ok(())
+    }
+
+    /// [`Pausable::unpause`] against an explicit timestamp.
+    fn unpause_at(&mut self, scope: u8, now: i64) -> Result<()> {
+        let active = self.paused_scopes(now);
+        require!(active & scope != 0, TicketError::NotPaused);
+
+        self.scopes = active & !scope;
+        if self.scopes == 0 {
+            *self = PauseState::default();
+        } else {
+            for (i, expiry) in self.expires_at.iter_mut().enumerate() {
+                if self.scopes & (1 << i) == 0 {
+                    *expiry = None;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Pausable for PauseState {
+    fn pause(&mut self, scope: u8, expires_at: Option<i64>) -> Result<()> {
+        self.pause_at(scope, expires_at, Clock::get()?.unix_timestamp)
+    }
+
+    fn unpause(&mut self, scope: u8) -> Result<()> {
+        self.unpause_at(scope, Clock::get()?.unix_timestamp)
+    }
+
+    fn paused_scopes(&self, now: i64) -> u8 {
+        self.expires_at
+            .iter()
+            .enumerate()
+            .filter(|(i, expiry)| self.scopes & (1 << i) != 0 && expiry.map_or(true, |t| now < t))
+            .fold(0, |scopes, (i, _)| scopes | 1 << i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINT: u8 = PauseScope::MINT;
+    const TRANSFER: u8 = PauseScope::TRANSFER;
+
+    #[test]
+    fn scopes_expire_independently() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT, Some(200), 0).unwrap();
+        state.pause_at(TRANSFER, Some(100), 0).unwrap();
+
+        assert_eq!(state.paused_scopes(99), MINT | TRANSFER);
+        assert_eq!(state.paused_scopes(100), MINT);
+        assert_eq!(state.paused_scopes(200), 0);
+    }
+
+    #[test]
+    fn open_ended_pause_beats_timed() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT, None, 0).unwrap();
+        state.pause_at(MINT | TRANSFER, Some(100), 10).unwrap();
+        assert_eq!(state.expires_at[0], None);
+        assert_eq!(state.paused_scopes(1_000), MINT);
+
+        let mut state = PauseState::default();
+        state.pause_at(MINT, Some(100), 0).unwrap();
+        state.pause_at(MINT | TRANSFER, None, 10).unwrap();
+        assert_eq!(state.paused_scopes(1_000), MINT | TRANSFER);
+    }
+
+    #[test]
+    fn longer_expiry_wins() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT, Some(300), 0).unwrap();
+        state.pause_at(MINT | TRANSFER, Some(100), 10).unwrap();
+        assert_eq!(state.expires_at[0], Some(300));
+        assert_eq!(state.expires_at[1], Some(100));
+
+        state.pause_at(MINT | PauseScope::REFUND, Some(500), 20).unwrap();
+        assert_eq!(state.expires_at[0], Some(500));
+    }
+
+    #[test]
+    fn repausing_only_active_scopes_fails() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT | TRANSFER, None, 0).unwrap();
+        assert!(state.pause_at(MINT, None, 10).is_err());
+        assert!(state.pause_at(0, None, 10).is_err());
+        assert!(state.pause_at(1 << 7, None, 10).is_err());
+        assert!(state.pause_at(PauseScope::REFUND, Some(10), 10).is_err());
+    }
+
+    #[test]
+    fn unpause_clears_expiry() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT, Some(100), 0).unwrap();
+        state.pause_at(TRANSFER, Some(200), 0).unwrap();
+
+        state.unpause_at(MINT, 10).unwrap();
+        assert_eq!(state.paused_scopes(10), TRANSFER);
+        assert_eq!(state.expires_at[0], None);
+
+        // Pausing MINT again starts fresh rather than inheriting the old expiry
+        state.pause_at(MINT, None, 20).unwrap();
+        assert_eq!(state.paused_scopes(1_000), MINT);
+
+        state.unpause_at(MINT | TRANSFER, 30).unwrap();
+        assert_eq!(state.scopes, 0);
+        assert_eq!(state.expires_at, [None; 5]);
+        assert!(state.unpause_at(MINT, 40).is_err());
+    }
+
+    #[test]
+    fn expired_scopes_can_be_paused_again() {
+        let mut state = PauseState::default();
+        state.pause_at(MINT, Some(100), 0).unwrap();
+        assert!(state.unpause_at(MINT, 100).is_err());
+        state.pause_at(MINT, Some(300), 150).unwrap();
+        assert_eq!(state.paused_scopes(299), MINT);
+        assert_eq!(state.paused_at, 150);
+    }
+}
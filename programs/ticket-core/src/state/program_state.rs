@@ -1,25 +1,47 @@
 use anchor_lang::prelude::*;
+use crate::state::PauseState;
+use crate::traits::Pausable;
 
 #[account]
 pub struct ProgramState {
     pub authority: Pubkey,
-    pub paused: bool,
-    pub paused_at: i64,
+    pub pause_state: PauseState,
     pub fee_recipient: Pubkey,
     pub platform_fee_basis_points: u16, // 100 = 1%
     pub total_events: u64,
     pub total_tickets_sold: u64,
     pub total_fees_collected: u64,
+    pub bump: u8,
+    pub pending_authority: Option<Pubkey>,  // Proposed, not yet accepted
+    pub timelock_delay: i64,                // Seconds a QueuedAction waits
+    pub queued_action_count: u64,           // Next QueuedAction id
 }
 
 impl ProgramState {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
-        1 + // paused
-        8 + // paused_at
+        PauseState::LEN + // pause_state
         32 + // fee_recipient
         2 + // platform_fee_basis_points
         8 + // total_events
         8 + // total_tickets_sold
-        8; // total_fees_collected
+        8 + // total_fees_collected
+        1 + // bump
+        33 + // pending_authority
+        8 + // timelock_delay
+        8; // queued_action_count
+}
+
+impl Pausable for ProgramState {
+    fn pause(&mut self, scope: u8, expires_at: Option<i64>) -> Result<()> {
+        self.pause_state.pause(scope, expires_at)
+    }
+
+    fn unpause(&mut self, scope: u8) -> Result<()> {
+        self.pause_state.unpause(scope)
+    }
+
+    fn paused_scopes(&self, now: i64) -> u8 {
+        self.pause_state.paused_scopes(now)
+    }
 }
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use super::Event;
+
+/// Tickets a wallet has bought for one event, checked against
+/// `Event.max_tickets_per_wallet` on every mint path.
+#[account]
+pub struct PurchaseRecord {
+    pub event: Pubkey,
+    pub wallet: Pubkey,
+    pub tickets_purchased: u32,             // Net of refunds
+    pub bump: u8,
+}
+
+impl PurchaseRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        32 + // wallet
+        4 + // tickets_purchased
+        1; // bump
+
+    /// Counts `quantity` more tickets for `wallet`, failing if that would take
+    /// it past the event's per-wallet cap. Also stamps a newly created record.
+    pub fn record_purchase(
+        &mut self,
+        event: &Account<Event>,
+        wallet: Pubkey,
+        quantity: u32,
+        bump: u8,
+    ) -> Result<()> {
+        let tickets_purchased = event.wallet_total_after(self.tickets_purchased, quantity)?;
+
+        self.event = event.key();
+        self.wallet = wallet;
+        self.tickets_purchased = tickets_purchased;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Fails if buying a hold of `quantity` would take `wallet` past the
+    /// event's per-wallet cap, so a hold never locks stock its buyer could not
+    /// purchase. Also stamps a newly created record.
+    pub fn check_hold(
+        &mut self,
+        event: &Account<Event>,
+        wallet: Pubkey,
+        quantity: u32,
+        bump: u8,
+    ) -> Result<()> {
+        event.wallet_total_after(self.tickets_purchased, quantity)?;
+
+        self.event = event.key();
+        self.wallet = wallet;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Frees up one ticket of the wallet's allowance.
+    pub fn record_refund(&mut self) {
+        self.tickets_purchased = self.tickets_purchased.saturating_sub(1);
+    }
+}
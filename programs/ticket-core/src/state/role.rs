@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_GATE_ID_LEN;
+use crate::errors::TicketError;
+use crate::state::Event;
+
+/// Staff permissions, as bit flags in `EventRole.permissions`.
+pub struct Permission;
+
+impl Permission {
+    pub const VALIDATE: u16 = 1 << 0;       // validate_entry
+    pub const MARK_USED: u16 = 1 << 1;      // mark_ticket_used
+    pub const FREEZE: u16 = 1 << 2;         // freeze_ticket
+    pub const UNFREEZE: u16 = 1 << 3;       // unfreeze_ticket
+    pub const BURN: u16 = 1 << 4;           // burn_ticket
+    pub const ALL: u16 = Self::VALIDATE | Self::MARK_USED | Self::FREEZE | Self::UNFREEZE | Self::BURN;
+
+    /// The error a signer without `permission` gets.
+    fn denied(permission: u16) -> TicketError {
+        match permission {
+            Self::FREEZE => TicketError::UnauthorizedFreezer,
+            Self::UNFREEZE => TicketError::UnauthorizedUnfreezer,
+            Self::BURN => TicketError::UnauthorizedBurner,
+            _ => TicketError::UnauthorizedValidator,
+        }
+    }
+}
+
+/// What one wallet may do on one event, at PDA `[b"role", event, wallet]`.
+/// The organizer never needs one.
+#[account]
+pub struct EventRole {
+    pub event: Pubkey,
+    pub wallet: Pubkey,
+    pub permissions: u16,                   // Permission bits
+    pub gate: Option<String>,               // Only scans at this gate_id; None = any gate
+    pub tiers: Option<u16>,                 // Bit per tier index; None = any tier
+    pub expires_at: Option<i64>,            // None = until revoked
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl EventRole {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        32 + // wallet
+        2 + // permissions
+        1 + 4 + MAX_GATE_ID_LEN + // gate
+        3 + // tiers
+        9 + // expires_at
+        32 + // granted_by
+        8 + // granted_at
+        1; // bump
+
+    /// Checks that `signer` may use `permission` on a ticket of `tier_index`
+    /// at `gate`. The organizer always may; anyone else needs `role`, which
+    /// the accounts struct has already tied to (event, signer) by its seeds.
+    /// Gate scope only applies where there is a gate; tier scope excludes
+    /// credentials without a tier, such as series passes.
+    pub fn authorize(
+        event: &Event,
+        signer: Pubkey,
+        role: Option<&EventRole>,
+        permission: u16,
+        gate: Option<&str>,
+        tier_index: Option<usize>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        Self::authorize_at(event, signer, role, permission, gate, tier_index, now)
+    }
+
+    /// [`EventRole::authorize`] against an explicit timestamp.
+    fn authorize_at(
+        event: &Event,
+        signer: Pubkey,
+        role: Option<&EventRole>,
+        permission: u16,
+        gate: Option<&str>,
+        tier_index: Option<usize>,
+        now: i64,
+    ) -> Result<()> {
+        if signer == event.organizer {
+            return Ok(());
+        }
+
+        let role = role.ok_or(Permission::denied(permission))?;
+        require!(role.permissions & permission == permission, Permission::denied(permission));
+
+        require!(role.expires_at.map_or(true, |t| now < t), TicketError::RoleExpired);
+
+        if let (Some(allowed), Some(gate)) = (&role.gate, gate) {
+            require!(allowed == gate, TicketError::OutsideRoleScope);
+        }
+        if let Some(mask) = role.tiers {
+            let tier = tier_index.ok_or(TicketError::OutsideRoleScope)?;
+            require!(tier < 16 && mask & (1 << tier) != 0, TicketError::OutsideRoleScope);
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments to `grant_role`. Granting again replaces the wallet's role.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct RoleConfig {
+    pub permissions: u16,
+    pub gate: Option<String>,
+    pub tiers: Option<u16>,
+    pub expires_at: Option<i64>,
+}
+
+impl RoleConfig {
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.permissions != 0 && self.permissions & !Permission::ALL == 0,
+            TicketError::InvalidPermissions
+        );
+        if let Some(gate) = &self.gate {
+            require!(
+                !gate.is_empty() && gate.len() <= MAX_GATE_ID_LEN,
+                TicketError::InvalidPermissions
+            );
+        }
+        require!(self.tiers != Some(0), TicketError::InvalidPermissions);
+        require!(self.expires_at.map_or(true, |t| t > now), TicketError::InvalidPermissions);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_000;
+
+    fn role(permissions: u16) -> EventRole {
+        EventRole {
+            event: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            permissions,
+            gate: None,
+            tiers: None,
+            expires_at: None,
+            granted_by: Pubkey::new_unique(),
+            granted_at: 0,
+            bump: 255,
+        }
+    }
+
+    fn config(permissions: u16) -> RoleConfig {
+        RoleConfig { permissions, gate: None, tiers: None, expires_at: None }
+    }
+
+    #[test]
+    fn organizer_needs_no_role() {
+        let event = Event { organizer: Pubkey::new_unique(), ..Default::default() };
+        assert!(EventRole::authorize_at(&event, event.organizer, None, Permission::BURN, Some("A"), Some(3), NOW).is_ok());
+    }
+
+    #[test]
+    fn permission_mask_is_enforced() {
+        let event = Event::default();
+        let staff = role(Permission::VALIDATE | Permission::MARK_USED);
+        let check = |role: Option<&EventRole>, permission| {
+            EventRole::authorize_at(&event, staff.wallet, role, permission, None, None, NOW)
+        };
+
+        assert!(check(Some(&staff), Permission::VALIDATE).is_ok());
+        assert!(check(Some(&staff), Permission::VALIDATE | Permission::MARK_USED).is_ok());
+        assert_eq!(check(Some(&staff), Permission::FREEZE).unwrap_err(), TicketError::UnauthorizedFreezer.into());
+        assert_eq!(check(Some(&staff), Permission::BURN).unwrap_err(), TicketError::UnauthorizedBurner.into());
+        assert_eq!(check(None, Permission::VALIDATE).unwrap_err(), TicketError::UnauthorizedValidator.into());
+    }
+
+    #[test]
+    fn expired_role_is_rejected() {
+        let event = Event::default();
+        let mut staff = role(Permission::VALIDATE);
+        staff.expires_at = Some(NOW);
+
+        let at = |now| EventRole::authorize_at(&event, staff.wallet, Some(&staff), Permission::VALIDATE, None, None, now);
+        assert!(at(NOW - 1).is_ok());
+        assert_eq!(at(NOW).unwrap_err(), TicketError::RoleExpired.into());
+    }
+
+    #[test]
+    fn gate_scope_only_applies_at_a_gate() {
+        let event = Event::default();
+        let mut staff = role(Permission::VALIDATE);
+        staff.gate = Some("north".to_string());
+
+        let at = |gate| EventRole::authorize_at(&event, staff.wallet, Some(&staff), Permission::VALIDATE, gate, None, NOW);
+        assert!(at(Some("north")).is_ok());
+        assert_eq!(at(Some("south")).unwrap_err(), TicketError::OutsideRoleScope.into());
+        assert!(at(None).is_ok());
+    }
+
+    #[test]
+    fn tier_scope_excludes_other_and_tierless_tickets() {
+        let event = Event::default();
+        let mut staff = role(Permission::VALIDATE);
+        staff.tiers = Some(0b101);
+
+        let at = |tier| EventRole::authorize_at(&event, staff.wallet, Some(&staff), Permission::VALIDATE, None, tier, NOW);
+        assert!(at(Some(0)).is_ok());
+        assert!(at(Some(2)).is_ok());
+        assert_eq!(at(Some(1)).unwrap_err(), TicketError::OutsideRoleScope.into());
+        assert_eq!(at(Some(16)).unwrap_err(), TicketError::OutsideRoleScope.into());
+        assert_eq!(at(None).unwrap_err(), TicketError::OutsideRoleScope.into());
+    }
+
+    #[test]
+    fn role_config_is_validated() {
+        assert!(config(Permission::ALL).validate(NOW).is_ok());
+        assert!(RoleConfig {
+            gate: Some("north".to_string()),
+            tiers: Some(1),
+            expires_at: Some(NOW + 1),
+            ..config(Permission::VALIDATE)
+        }
+        .validate(NOW)
+        .is_ok());
+
+        let invalid = [
+            config(0),
+            config(1 << 5),
+            RoleConfig { gate: Some(String::new()), ..config(Permission::VALIDATE) },
+            RoleConfig { gate: Some("g".repeat(MAX_GATE_ID_LEN + 1)), ..config(Permission::VALIDATE) },
+            RoleConfig { tiers: Some(0), ..config(Permission::VALIDATE) },
+            RoleConfig { expires_at: Some(NOW), ..config(Permission::VALIDATE) },
+        ];
+        for config in invalid {
+            assert_eq!(config.validate(NOW).unwrap_err(), TicketError::InvalidPermissions.into());
+        }
+    }
+}
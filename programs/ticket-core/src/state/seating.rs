@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_SECTIONS, MAX_SECTION_NAME_LEN};
+use crate::errors::TicketError;
+
+/// Reserved-seating layout for an event. Each section belongs to one tier and
+/// is a grid of `rows` x `seats_per_row`; seats are numbered from zero.
+#[account]
+pub struct SeatingMap {
+    pub event: Pubkey,
+    pub sections: Vec<Section>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Section {
+    pub name: String,
+    pub tier_index: u8,
+    pub rows: u16,
+    pub seats_per_row: u16,
+}
+
+impl Section {
+    pub const LEN: usize = 4 + MAX_SECTION_NAME_LEN + // name
+        1 + // tier_index
+        2 + // rows
+        2; // seats_per_row
+}
+
+/// Seat stored on a ticket; indexes into `SeatingMap.sections`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SeatAssignment {
+    pub section: u8,
+    pub row: u16,
+    pub seat: u16,
+}
+
+impl SeatAssignment {
+    pub const LEN: usize = 1 + 2 + 2;
+}
+
+/// Marker PDA at `[b"seat", event, section, row, seat]`. Its existence is the
+/// claim: creating it fails if another ticket already holds the seat.
+#[account]
+pub struct SeatClaim {
+    pub event: Pubkey,
+    pub ticket: Pubkey,
+    pub seat: SeatAssignment,
+    pub bump: u8,
+}
+
+impl SeatClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        32 + // ticket
+        SeatAssignment::LEN + // seat
+        1; // bump
+}
+
+impl SeatingMap {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        4 + (Section::LEN * MAX_SECTIONS) + // sections
+        1; // bump
+
+    /// Checks that `seat` exists and belongs to the ticket's tier.
+    pub fn validate_seat(&self, seat: &SeatAssignment, tier_index: usize) -> Result<()> {
+        let section = self
+            .sections
+            .get(seat.section as usize)
+            .ok_or(TicketError::InvalidSeat)?;
+        require!(
+            section.tier_index as usize == tier_index
+                && seat.row < section.rows
+                && seat.seat < section.seats_per_row,
+            TicketError::InvalidSeat
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_SERIES_EVENTS, MAX_SERIES_NAME_LEN};
+
+/// Groups events sold together, e.g. the days of a festival. Passes are sold
+/// at series level; every member event sets aside `pass_supply` of its own
+/// capacity so passes and day tickets together can never oversell a day.
+#[account]
+pub struct EventSeries {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub series_id: u64,
+    pub name: String,
+    pub events: Vec<Pubkey>,                // Index = bit in SeriesPass.entered_events
+    pub pass_refunds: Vec<u32>,             // Per event: passes refunded for its cancellation
+    pub pass_price: u64,
+    pub pass_supply: u32,
+    pub passes_sold: u32,
+    pub next_pass_id: u64,
+    pub ends_at: i64,                       // Latest end of any member event
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl EventSeries {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // treasury
+        8 + // series_id
+        4 + MAX_SERIES_NAME_LEN + // name
+        4 + (32 * MAX_SERIES_EVENTS) + // events
+        4 + (4 * MAX_SERIES_EVENTS) + // pass_refunds
+        8 + // pass_price
+        4 + // pass_supply
+        4 + // passes_sold
+        8 + // next_pass_id
+        8 + // ends_at
+        8 + // created_at
+        1; // bump
+
+    pub fn event_index(&self, event: &Pubkey) -> Option<usize> {
+        self.events.iter().position(|e| e == event)
+    }
+
+    /// What a pass gets back for one cancelled member event: an equal share
+    /// of what was escrowed for it. The platform fee is not refundable.
+    pub fn pass_refund(&self, escrowed: u64) -> u64 {
+        escrowed / self.events.len().max(1) as u64
+    }
+}
+
+/// Multi-event pass. Entry is tracked per member event, so a pass admits
+/// once to each day.
+#[account]
+pub struct SeriesPass {
+    pub series: Pubkey,
+    pub pass_id: u64,
+    pub owner: Pubkey,
+    pub purchase_price: u64,
+    pub platform_fee: u64,
+    pub purchased_at: i64,
+    pub entered_events: u32,                // Bit i set = entered series.events[i]
+    pub refunded_events: u32,               // Bit i set = refunded for series.events[i]
+    pub bump: u8,
+}
+
+impl SeriesPass {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // series
+        8 + // pass_id
+        32 + // owner
+        8 + // purchase_price
+        8 + // platform_fee
+        8 + // purchased_at
+        4 + // entered_events
+        4 + // refunded_events
+        1 + // bump
+        64; // padding
+
+    pub fn has_entered(&self, event_index: usize) -> bool {
+        self.entered_events & (1 << event_index) != 0
+    }
+
+    pub fn record_entry(&mut self, event_index: usize) {
+        self.entered_events |= 1 << event_index;
+    }
+
+    pub fn is_refunded(&self, event_index: usize) -> bool {
+        self.refunded_events & (1 << event_index) != 0
+    }
+
+    pub fn record_refund(&mut self, event_index: usize) {
+        self.refunded_events |= 1 << event_index;
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use super::{Event, SeatAssignment};
 
 #[account]
 pub struct Ticket {
@@ -46,6 +47,10 @@ pub struct Ticket {
     pub insurance_coverage: Option<u64>,    // Insurance amount
     pub special_benefits: Vec<String>,      // VIP perks, etc
     pub qr_code_hash: Option<[u8; 32]>,    // For offline validation
+    pub platform_fee: u64,                  // Platform share of purchase_price
+    pub purchaser: Pubkey,                  // Wallet that paid, charged against the per-wallet cap
+    pub is_comp: bool,                      // Zero-price reservation, not a sale
+    pub seat: Option<SeatAssignment>,       // Reserved seat, if the tier is seated
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -83,6 +88,7 @@ pub enum TransferType {
     Gift,
     Marketplace,
     Admin,
+    Comp,
 }
 
 impl Ticket {
@@ -126,5 +132,60 @@ impl Ticket {
         9 + // insurance_coverage
         (50 * 10) + // special_benefits
         33 + // qr_code_hash
+        8 + // platform_fee
+        32 + // purchaser
+        1 + // is_comp
+        1 + SeatAssignment::LEN + // seat
         512; // padding
+
+    /// Populates a freshly initialized ticket account for its first owner,
+    /// taking the event's next sequential ticket id.
+    pub fn issue(
+        &mut self,
+        event: &Account<Event>,
+        owner: Pubkey,
+        tier_index: usize,
+        purchase_price: u64,
+        purchased_at: i64,
+        bump: u8,
+    ) {
+        self.version = 1;
+        self.ticket_id = event.next_ticket_id;
+        self.event = event.key();
+        self.owner = owner;
+        self.original_owner = owner;
+        self.purchaser = owner;
+        self.tier_index = tier_index;
+        self.purchase_price = purchase_price;
+        self.purchased_at = purchased_at;
+        self.status = TicketStatus::Valid;
+        self.refund_eligible = event.refund_policy.allows_refunds();
+        self.refund_deadline = event.refund_policy.refund_deadline(event.start_time);
+        self.bump = bump;
+    }
+
+    /// Hands a comp to its holder. Unassigned comps are owned by the default
+    /// pubkey, which nobody can sign for.
+    pub fn assign_comp(&mut self, from: Pubkey, recipient: Pubkey, timestamp: i64) {
+        self.owner = recipient;
+        self.original_owner = recipient;
+        self.transfer_history.push(TransferRecord {
+            from,
+            to: recipient,
+            timestamp,
+            memo: None,
+            transfer_type: TransferType::Comp,
+        });
+    }
+}
+
+#[event]
+pub struct TicketMintedEvent {
+    pub event: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub owner: Pubkey,
+    pub tier_index: u8,
+    pub purchase_price: u64,
+    pub timestamp: i64,
 }
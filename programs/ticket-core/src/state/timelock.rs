@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    MAX_PLATFORM_FEE_BASIS_POINTS, MAX_TIMELOCK_DELAY_SECONDS, MIN_TIMELOCK_DELAY_SECONDS,
+    TIMELOCK_GRACE_PERIOD_SECONDS,
+};
+use crate::errors::TicketError;
+
+/// A `ProgramState` change waiting out `ProgramState.timelock_delay`. The
+/// account is public for the whole delay, so users can react (or the
+/// authority can cancel) before it lands.
+#[account]
+pub struct QueuedAction {
+    pub action_id: u64,
+    pub action: TimelockedAction,
+    pub queued_by: Pubkey,                  // Program authority at queue time
+    pub payer: Pubkey,                      // Gets the rent back on execute or cancel
+    pub queued_at: i64,
+    pub eta: i64,                           // Executable from, for TIMELOCK_GRACE_PERIOD_SECONDS
+    pub bump: u8,
+}
+
+impl QueuedAction {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // action_id
+        TimelockedAction::LEN + // action
+        32 + // queued_by
+        32 + // payer
+        8 + // queued_at
+        8 + // eta
+        1; // bump
+
+    /// Fails unless the action can execute at `now`: its eta has passed, the
+    /// grace period hasn't, and `authority` is still the one that queued it.
+    /// An action left waiting past the grace period no longer reflects a
+    /// decision anyone is watching, so it has to be queued again.
+    pub fn check_executable(&self, authority: Pubkey, now: i64) -> Result<()> {
+        require!(now >= self.eta, TicketError::TimelockNotElapsed);
+        require!(
+            now - self.eta <= TIMELOCK_GRACE_PERIOD_SECONDS,
+            TicketError::TimelockActionExpired
+        );
+        require_keys_eq!(self.queued_by, authority, TicketError::Unauthorized);
+        Ok(())
+    }
+}
+
+/// Changes that only take effect after the timelock delay.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum TimelockedAction {
+    /// Any fee change may be queued; increases can only be made this way.
+    UpdateFee { platform_fee_basis_points: u16 },
+    /// Starts a program authority handover; the new key still has to accept.
+    ProposeAuthority { new_authority: Pubkey },
+    SetTimelockDelay { delay: i64 },
+}
+
+impl TimelockedAction {
+    pub const LEN: usize = 1 + 32; // variant + largest payload (ProposeAuthority)
+
+    /// Rejects actions that could never execute.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::UpdateFee { platform_fee_basis_points } => require!(
+                *platform_fee_basis_points <= MAX_PLATFORM_FEE_BASIS_POINTS,
+                TicketError::FeeTooHigh
+            ),
+            Self::ProposeAuthority { new_authority } => require!(
+                *new_authority != Pubkey::default(),
+                TicketError::InvalidPendingAuthority
+            ),
+            Self::SetTimelockDelay { delay } => require!(
+                (MIN_TIMELOCK_DELAY_SECONDS..=MAX_TIMELOCK_DELAY_SECONDS).contains(delay),
+                TicketError::InvalidTimelockDelay
+            ),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(eta: i64, queued_by: Pubkey) -> QueuedAction {
+        QueuedAction {
+            action_id: 0,
+            action: TimelockedAction::UpdateFee { platform_fee_basis_points: 300 },
+            queued_by,
+            payer: queued_by,
+            queued_at: eta - MIN_TIMELOCK_DELAY_SECONDS,
+            eta,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn rejects_actions_that_could_never_execute() {
+        let fee = |bps| TimelockedAction::UpdateFee { platform_fee_basis_points: bps };
+        assert!(fee(MAX_PLATFORM_FEE_BASIS_POINTS).validate().is_ok());
+        assert_eq!(fee(MAX_PLATFORM_FEE_BASIS_POINTS + 1).validate().unwrap_err(), TicketError::FeeTooHigh.into());
+
+        let propose = |new_authority| TimelockedAction::ProposeAuthority { new_authority };
+        assert!(propose(Pubkey::new_unique()).validate().is_ok());
+        assert_eq!(
+            propose(Pubkey::default()).validate().unwrap_err(),
+            TicketError::InvalidPendingAuthority.into()
+        );
+
+        let delay = |delay| TimelockedAction::SetTimelockDelay { delay };
+        assert!(delay(MIN_TIMELOCK_DELAY_SECONDS).validate().is_ok());
+        assert!(delay(MAX_TIMELOCK_DELAY_SECONDS).validate().is_ok());
+        for bad in [MIN_TIMELOCK_DELAY_SECONDS - 1, MAX_TIMELOCK_DELAY_SECONDS + 1, 0, -1] {
+            assert_eq!(delay(bad).validate().unwrap_err(), TicketError::InvalidTimelockDelay.into());
+        }
+    }
+
+    #[test]
+    fn executes_only_between_eta_and_the_grace_period() {
+        let authority = Pubkey::new_unique();
+        let action = queued(1_000, authority);
+
+        assert_eq!(
+            action.check_executable(authority, 999).unwrap_err(),
+            TicketError::TimelockNotElapsed.into()
+        );
+        assert!(action.check_executable(authority, 1_000).is_ok());
+        assert!(action.check_executable(authority, 1_000 + TIMELOCK_GRACE_PERIOD_SECONDS).is_ok());
+        assert_eq!(
+            action.check_executable(authority, 1_001 + TIMELOCK_GRACE_PERIOD_SECONDS).unwrap_err(),
+            TicketError::TimelockActionExpired.into()
+        );
+    }
+
+    #[test]
+    fn actions_from_an_earlier_authority_are_stale() {
+        let action = queued(1_000, Pubkey::new_unique());
+        assert!(action.check_executable(Pubkey::new_unique(), 1_000).is_err());
+    }
+}
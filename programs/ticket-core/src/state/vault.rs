@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::errors::TicketError;
+use crate::utils::math::*;
+
+/// Per-event escrow holding ticket revenue. Sales are deposited here, refunds
+/// are paid from here, and the organizer is paid only through settlement.
+#[account]
+pub struct EventVault {
+    pub event: Pubkey,
+    pub total_collected: u64,               // Gross sales deposited
+    pub total_refunded: u64,                // Paid back to buyers
+    pub total_settled: u64,                 // Released to the organizer
+    pub pending_refunds: u64,               // Approved-for-review refunds not yet paid
+    pub bump: u8,
+}
+
+impl EventVault {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        8 + // total_collected
+        8 + // total_refunded
+        8 + // total_settled
+        8 + // pending_refunds
+        1; // bump
+
+    /// Funds not yet refunded or settled.
+    pub fn balance(&self) -> Result<u64> {
+        let paid_out = safe_add(self.total_refunded, self.total_settled)?;
+        safe_sub(self.total_collected, paid_out)
+    }
+
+    /// Funds the organizer may settle now, keeping pending refunds covered.
+    pub fn settleable(&self) -> Result<u64> {
+        Ok(self.balance()?.saturating_sub(self.pending_refunds))
+    }
+
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = safe_add(self.total_collected, amount)?;
+        Ok(())
+    }
+
+    /// Moves lamports out of the vault. The vault is program-owned, so this is
+    /// a direct debit; accounting fields are updated by the caller.
+    pub fn pay_out(vault: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+        let rent_minimum = Rent::get()?.minimum_balance(Self::LEN);
+        let spendable = vault.lamports().saturating_sub(rent_minimum);
+        require!(amount <= spendable, TicketError::InsufficientFunds);
+
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? = safe_add(recipient.lamports(), amount)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Presale list for one event. Eligible wallets are committed to as a merkle
+/// root so the list itself never has to live on-chain.
+#[account]
+pub struct Whitelist {
+    pub event: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_per_wallet: u8,                 // Default allocation for leaves without one
+    pub active: bool,
+    pub discount_basis_points: u16,         // Off the tier price
+    pub total_minted: u32,
+    pub bump: u8,
+}
+
+impl Whitelist {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // event
+        32 + // merkle_root
+        1 + // max_per_wallet
+        1 + // active
+        2 + // discount_basis_points
+        4 + // total_minted
+        1 + // bump
+        64; // padding
+}
+
+/// Tickets a wallet has minted against a whitelist. Survives root rotation,
+/// so re-listing a wallet does not reset its count.
+#[account]
+pub struct WhitelistClaim {
+    pub whitelist: Pubkey,
+    pub wallet: Pubkey,
+    pub minted: u8,
+    pub bump: u8,
+}
+
+impl WhitelistClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // whitelist
+        32 + // wallet
+        1 + // minted
+        1; // bump
+}
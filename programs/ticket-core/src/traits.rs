@@ -1,10 +1,29 @@
 //! Common traits for the ticket system
 use anchor_lang::prelude::*;
 
+/// Operation classes that can be paused independently, as bit flags.
+pub struct PauseScope;
+
+impl PauseScope {
+    pub const MINT: u8 = 1 << 0;            // Sales, holds, comps, series passes
+    pub const TRANSFER: u8 = 1 << 1;        // Direct and delegated transfers
+    pub const VALIDATE: u8 = 1 << 2;        // Gate scans, marking used, burning
+    pub const REFUND: u8 = 1 << 3;          // Refund requests and payouts
+    pub const ADMIN: u8 = 1 << 4;           // Organizer and platform management
+    pub const ALL: u8 = Self::MINT | Self::TRANSFER | Self::VALIDATE | Self::REFUND | Self::ADMIN;
+}
+
 pub trait Pausable {
-    fn pause(&mut self) -> Result<()>;
-    fn unpause(&mut self) -> Result<()>;
-    fn is_paused(&self) -> bool;
+    /// Pauses each class in `scope` until `expires_at`, or until unpaused if `None`.
+    fn pause(&mut self, scope: u8, expires_at: Option<i64>) -> Result<()>;
+    fn unpause(&mut self, scope: u8) -> Result<()>;
+    /// Scopes paused at `now`; an expired pause no longer counts.
+    fn paused_scopes(&self, now: i64) -> u8;
+
+    fn is_paused(&self, scope: u8) -> bool {
+        let now = Clock::get().map(|clock| clock.unix_timestamp).unwrap_or_default();
+        self.paused_scopes(now) & scope != 0
+    }
 }
 
 pub trait Versioned {
@@ -0,0 +1,268 @@
+//! Account creation and payment helpers shared by instructions
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use crate::{errors::TicketError, state::{Event, EventRole, EventVault, QueuedAction, SeatAssignment, SeatClaim, Ticket}};
+use crate::state::program_state::ProgramState;
+use crate::utils::math::*;
+
+/// Splits a sale payment from the buyer: the platform fee goes to the program
+/// state account (withdrawn later via `withdraw_fees`) and the rest is escrowed
+/// in the event vault. Returns the fee charged.
+pub fn collect_payment<'info>(
+    vault: &mut Account<'info, EventVault>,
+    program_state: &mut Account<'info, ProgramState>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let fee = calculate_percentage(amount, program_state.platform_fee_basis_points)?;
+    let net = safe_sub(amount, fee)?;
+
+    pay(payer, &program_state.to_account_info(), system_program, fee)?;
+    pay(payer, &vault.to_account_info(), system_program, net)?;
+
+    program_state.total_fees_collected = safe_add(program_state.total_fees_collected, fee)?;
+    vault.record_deposit(net)?;
+    Ok(fee)
+}
+
+fn pay<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Creates the ticket PDA for `event.next_ticket_id` from a remaining account.
+/// `issue` fills in the zeroed ticket (it receives the PDA bump); the caller
+/// advances `next_ticket_id`.
+pub fn create_ticket_account<'info, F>(
+    event: &Account<'info, Event>,
+    ticket_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    issue: F,
+) -> Result<Ticket>
+where
+    F: FnOnce(&mut Ticket, u8) -> Result<()>,
+{
+    let event_key = event.key();
+    let ticket_id_bytes = event.next_ticket_id.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"ticket", event_key.as_ref(), ticket_id_bytes.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ticket_info.key(), expected, TicketError::InvalidAccount);
+    require!(ticket_info.data_is_empty(), TicketError::InvalidAccount);
+
+    create_pda(
+        ticket_info,
+        payer,
+        system_program,
+        &[b"ticket", event_key.as_ref(), ticket_id_bytes.as_ref(), &[bump]],
+        Ticket::LEN,
+    )?;
+
+    // Same approach as Anchor's `init`: start from zeroed data, then write back
+    let mut ticket = Ticket::try_deserialize_unchecked(&mut &ticket_info.try_borrow_data()?[..])?;
+    issue(&mut ticket, bump)?;
+    ticket.try_serialize(&mut &mut ticket_info.try_borrow_mut_data()?[..])?;
+
+    Ok(ticket)
+}
+
+/// Claims a reserved seat for `ticket` by creating its seat PDA. Fails with
+/// `SeatTaken` if the PDA already exists, so two mints can never share a seat.
+/// The caller checks the seat against the event's seating map.
+pub fn claim_seat<'info>(
+    event: &Account<'info, Event>,
+    seat_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seat: SeatAssignment,
+    ticket: Pubkey,
+) -> Result<()> {
+    let event_key = event.key();
+    let section = [seat.section];
+    let row = seat.row.to_le_bytes();
+    let number = seat.seat.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"seat", event_key.as_ref(), &section, &row, &number],
+        &crate::ID,
+    );
+    require_keys_eq!(seat_info.key(), expected, TicketError::InvalidAccount);
+    require!(seat_info.data_is_empty(), TicketError::SeatTaken);
+
+    create_pda(
+        seat_info,
+        payer,
+        system_program,
+        &[b"seat", event_key.as_ref(), &section, &row, &number, &[bump]],
+        SeatClaim::LEN,
+    )?;
+
+    let claim = SeatClaim {
+        event: event_key,
+        ticket,
+        seat,
+        bump,
+    };
+    claim.try_serialize(&mut &mut seat_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Creates the `QueuedAction` PDA for `action_id` when it can't be an Anchor
+/// `init` account, i.e. when a multisig proposal queues the action. `fill`
+/// receives the zeroed account and its bump.
+pub fn create_queued_action_account<'info, F>(
+    action_id: u64,
+    queued_action_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fill: F,
+) -> Result<()>
+where
+    F: FnOnce(&mut QueuedAction, u8) -> Result<()>,
+{
+    let action_id_bytes = action_id.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"queued_action", action_id_bytes.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(queued_action_info.key(), expected, TicketError::InvalidAccount);
+    require!(queued_action_info.data_is_empty(), TicketError::InvalidAccount);
+
+    create_pda(
+        queued_action_info,
+        payer,
+        system_program,
+        &[b"queued_action", action_id_bytes.as_ref(), &[bump]],
+        QueuedAction::LEN,
+    )?;
+
+    let mut queued_action =
+        QueuedAction::try_deserialize_unchecked(&mut &queued_action_info.try_borrow_data()?[..])?;
+    fill(&mut queued_action, bump)?;
+    queued_action.try_serialize(&mut &mut queued_action_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Creates the `EventRole` PDA for (`event`, `wallet`), or loads it if the
+/// wallet already has one, when it can't be an Anchor `init_if_needed`
+/// account, i.e. when a multisig proposal grants the role. `fill` receives the
+/// role (zeroed if new) and its bump.
+pub fn init_role_account<'info, F>(
+    event: &Account<'info, Event>,
+    wallet: Pubkey,
+    role_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fill: F,
+) -> Result<()>
+where
+    F: FnOnce(&mut EventRole, u8) -> Result<()>,
+{
+    let event_key = event.key();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"role", event_key.as_ref(), wallet.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(role_info.key(), expected, TicketError::InvalidAccount);
+
+    let mut role = if role_info.data_is_empty() {
+        create_pda(
+            role_info,
+            payer,
+            system_program,
+            &[b"role", event_key.as_ref(), wallet.as_ref(), &[bump]],
+            EventRole::LEN,
+        )?;
+        EventRole::try_deserialize_unchecked(&mut &role_info.try_borrow_data()?[..])?
+    } else {
+        require_keys_eq!(*role_info.owner, crate::ID, TicketError::InvalidAccount);
+        EventRole::try_deserialize(&mut &role_info.try_borrow_data()?[..])?
+    };
+    fill(&mut role, bump)?;
+    role.try_serialize(&mut &mut role_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Closes a program account the way Anchor's `close` constraint does, for
+/// accounts that are only known at runtime.
+pub fn close_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    **destination.try_borrow_mut_lamports()? = safe_add(destination.lamports(), account.lamports())?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&anchor_lang::solana_program::system_program::ID);
+    account.realloc(0, false).map_err(Into::into)
+}
+
+/// Creates a program-owned PDA. Like Anchor's `init`, this also works when
+/// someone has already sent lamports to the address, which would otherwise
+/// make `create_account` fail and the PDA permanently uncreatable.
+fn create_pda<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    pay(payer, account, system_program, rent.saturating_sub(current_lamports))?;
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
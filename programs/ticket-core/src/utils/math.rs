@@ -1,32 +1,235 @@
 //! Safe math operations
 use anchor_lang::prelude::*;
 use crate::errors::TicketError;
+use crate::state::{PriceStep, PricingCurve};
+
+/// Fixed-point scale for rates and multipliers: 10,000 bps = 100% = 1x.
+pub const BPS_DENOMINATOR: u64 = 10_000;
 
 pub fn safe_add(a: u64, b: u64) -> Result<u64> {
-    a.checked_add(b).ok_or(TicketError::MathOverflow.into())
+    a.checked_add(b).ok_or(TicketError::ArithmeticOverflow.into())
 }
 
 pub fn safe_sub(a: u64, b: u64) -> Result<u64> {
-    a.checked_sub(b).ok_or(TicketError::MathUnderflow.into())
+    a.checked_sub(b).ok_or(TicketError::ArithmeticUnderflow.into())
 }
 
 pub fn safe_mul(a: u64, b: u64) -> Result<u64> {
-    a.checked_mul(b).ok_or(TicketError::MathOverflow.into())
+    a.checked_mul(b).ok_or(TicketError::ArithmeticOverflow.into())
 }
 
 pub fn calculate_percentage(amount: u64, basis_points: u16) -> Result<u64> {
     let percentage = safe_mul(amount, basis_points as u64)?;
-    percentage.checked_div(10000).ok_or(TicketError::MathOverflow.into())
-}
-
-pub fn calculate_surge_multiplier(sold: u32, capacity: u32) -> Result<u16> {
-    let utilization = (sold as f64) / (capacity as f64);
-    let multiplier = match utilization {
-        u if u < 0.5 => 100,   // 1x
-        u if u < 0.7 => 110,   // 1.1x
-        u if u < 0.8 => 125,   // 1.25x
-        u if u < 0.9 => 150,   // 1.5x
-        _ => 200,              // 2x
-    };
-    Ok(multiplier)
+    percentage.checked_div(BPS_DENOMINATOR).ok_or(TicketError::ArithmeticOverflow.into())
+}
+
+/// The `index`-th of `parts` equal shares of `total`. The last share also
+/// takes the remainder, so the shares always sum to `total`.
+pub fn share_of(total: u64, parts: u64, index: u64) -> u64 {
+    let share = total / parts;
+    if index + 1 == parts {
+        share + total % parts
+    } else {
+        share
+    }
+}
+
+/// Share of capacity sold, in basis points, capped at 10,000.
+/// An empty capacity counts as sold out.
+pub fn utilization_bps(sold: u32, capacity: u32) -> u16 {
+    if capacity == 0 || sold >= capacity {
+        return BPS_DENOMINATOR as u16;
+    }
+    (sold as u64 * BPS_DENOMINATOR / capacity as u64) as u16
+}
+
+/// Multiplier of the highest step whose threshold has been reached; 1x before
+/// the first step. Steps must be sorted by `utilization_bps`.
+pub fn step_multiplier_bps(steps: &[PriceStep], utilization_bps: u16) -> u32 {
+    steps
+        .iter()
+        .take_while(|step| step.utilization_bps <= utilization_bps)
+        .last()
+        .map(|step| step.multiplier_bps)
+        .unwrap_or(BPS_DENOMINATOR as u32)
+}
+
+/// 1x at zero utilization rising linearly to `max_multiplier_bps` at sell-out.
+pub fn linear_multiplier_bps(max_multiplier_bps: u32, utilization_bps: u16) -> Result<u32> {
+    let base = BPS_DENOMINATOR as u32;
+    let rise = max_multiplier_bps.saturating_sub(base) as u64;
+    let increase = rise * utilization_bps as u64 / BPS_DENOMINATOR;
+    u32::try_from(base as u64 + increase).map_err(|_| TicketError::ArithmeticOverflow.into())
+}
+
+/// Compounds `growth_bps` for every full `step_bps` of utilization, i.e. a
+/// discrete exponential bonding curve. `step_bps` must be non-zero.
+pub fn exponential_multiplier_bps(growth_bps: u32, step_bps: u16, utilization_bps: u16) -> Result<u32> {
+    require!(step_bps > 0, TicketError::InvalidPricingCurve);
+    let steps = utilization_bps / step_bps;
+
+    let mut multiplier = BPS_DENOMINATOR;
+    for _ in 0..steps {
+        multiplier = safe_mul(multiplier, BPS_DENOMINATOR + growth_bps as u64)? / BPS_DENOMINATOR;
+    }
+    u32::try_from(multiplier).map_err(|_| TicketError::ArithmeticOverflow.into())
+}
+
+pub fn curve_multiplier_bps(curve: &PricingCurve, utilization_bps: u16) -> Result<u32> {
+    match curve {
+        PricingCurve::StepTable { steps } => Ok(step_multiplier_bps(steps, utilization_bps)),
+        PricingCurve::Linear { max_multiplier_bps } => {
+            linear_multiplier_bps(*max_multiplier_bps, utilization_bps)
+        },
+        PricingCurve::Exponential { growth_bps, step_bps } => {
+            exponential_multiplier_bps(*growth_bps, *step_bps, utilization_bps)
+        },
+    }
+}
+
+/// `price * multiplier_bps / 10,000` without intermediate overflow.
+pub fn apply_multiplier_bps(price: u64, multiplier_bps: u32) -> Result<u64> {
+    let scaled = price as u128 * multiplier_bps as u128 / BPS_DENOMINATOR as u128;
+    u64::try_from(scaled).map_err(|_| TicketError::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_steps() -> Vec<PriceStep> {
+        match PricingCurve::default() {
+            PricingCurve::StepTable { steps } => steps,
+            _ => unreachable!(),
+        }
+    }
+
+    fn all_curves() -> Vec<PricingCurve> {
+        vec![
+            PricingCurve::default(),
+            PricingCurve::Linear { max_multiplier_bps: 30_000 },
+            PricingCurve::Exponential { growth_bps: 500, step_bps: 1_000 },
+            PricingCurve::Exponential { growth_bps: 10_000, step_bps: 100 },
+        ]
+    }
+
+    #[test]
+    fn shares_sum_to_total() {
+        for (total, parts) in [(0u64, 1u64), (10, 3), (250, 4), (7, 7), (u64::MAX, 10)] {
+            let shares: Vec<u64> = (0..parts).map(|i| share_of(total, parts, i)).collect();
+            assert_eq!(shares.iter().map(|s| *s as u128).sum::<u128>(), total as u128);
+            assert!(shares[..parts as usize - 1].iter().all(|s| *s == total / parts));
+        }
+        assert_eq!(share_of(10, 3, 2), 4);
+    }
+
+    #[test]
+    fn utilization_is_bounded() {
+        assert_eq!(utilization_bps(0, 100), 0);
+        assert_eq!(utilization_bps(50, 100), 5_000);
+        assert_eq!(utilization_bps(1, 3), 3_333);
+        assert_eq!(utilization_bps(100, 100), 10_000);
+        assert_eq!(utilization_bps(150, 100), 10_000);
+        assert_eq!(utilization_bps(0, 0), 10_000);
+        assert_eq!(utilization_bps(u32::MAX - 1, u32::MAX), 9_999);
+    }
+
+    #[test]
+    fn utilization_is_monotonic() {
+        for capacity in [1u32, 7, 100, 1_000, 65_537] {
+            let mut previous = 0;
+            for sold in 0..=capacity.min(2_000) {
+                let current = utilization_bps(sold, capacity);
+                assert!(current >= previous);
+                previous = current;
+            }
+        }
+    }
+
+    #[test]
+    fn default_step_table_matches_legacy_surge_table() {
+        let steps = default_steps();
+        assert_eq!(step_multiplier_bps(&steps, 0), 10_000);
+        assert_eq!(step_multiplier_bps(&steps, 4_999), 10_000);
+        assert_eq!(step_multiplier_bps(&steps, 5_000), 11_000);
+        assert_eq!(step_multiplier_bps(&steps, 6_999), 11_000);
+        assert_eq!(step_multiplier_bps(&steps, 7_000), 12_500);
+        assert_eq!(step_multiplier_bps(&steps, 8_000), 15_000);
+        assert_eq!(step_multiplier_bps(&steps, 9_000), 20_000);
+        assert_eq!(step_multiplier_bps(&steps, 10_000), 20_000);
+    }
+
+    #[test]
+    fn empty_step_table_is_flat() {
+        for utilization in (0..=10_000).step_by(500) {
+            assert_eq!(step_multiplier_bps(&[], utilization), 10_000);
+        }
+    }
+
+    #[test]
+    fn linear_curve_endpoints() {
+        assert_eq!(linear_multiplier_bps(30_000, 0).unwrap(), 10_000);
+        assert_eq!(linear_multiplier_bps(30_000, 5_000).unwrap(), 20_000);
+        assert_eq!(linear_multiplier_bps(30_000, 10_000).unwrap(), 30_000);
+        // A maximum below 1x never discounts
+        assert_eq!(linear_multiplier_bps(5_000, 10_000).unwrap(), 10_000);
+        assert_eq!(linear_multiplier_bps(u32::MAX, 10_000).unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn exponential_curve_compounds() {
+        assert_eq!(exponential_multiplier_bps(1_000, 5_000, 0).unwrap(), 10_000);
+        assert_eq!(exponential_multiplier_bps(1_000, 5_000, 4_999).unwrap(), 10_000);
+        assert_eq!(exponential_multiplier_bps(1_000, 5_000, 5_000).unwrap(), 11_000);
+        assert_eq!(exponential_multiplier_bps(1_000, 5_000, 10_000).unwrap(), 12_100);
+        assert_eq!(exponential_multiplier_bps(0, 100, 10_000).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn exponential_curve_rejects_zero_step() {
+        assert!(exponential_multiplier_bps(1_000, 0, 5_000).is_err());
+    }
+
+    #[test]
+    fn exponential_curve_overflow_is_an_error() {
+        // Doubling 100 times cannot fit in u32 (or u64)
+        assert!(exponential_multiplier_bps(10_000, 100, 10_000).is_err());
+        assert!(exponential_multiplier_bps(u32::MAX, 1, 10_000).is_err());
+    }
+
+    #[test]
+    fn curves_are_monotonic() {
+        for curve in all_curves() {
+            let mut previous = 0;
+            for utilization in 0..=10_000u16 {
+                let current = match curve_multiplier_bps(&curve, utilization) {
+                    Ok(multiplier) => multiplier,
+                    Err(_) => break, // overflow past this point is rejected, not wrapped
+                };
+                assert!(current >= previous, "{:?} decreased at {}", curve, utilization);
+                assert!(current >= 10_000, "{:?} discounted at {}", curve, utilization);
+                previous = current;
+            }
+        }
+    }
+
+    #[test]
+    fn apply_multiplier_scales_and_checks_overflow() {
+        assert_eq!(apply_multiplier_bps(1_000, 10_000).unwrap(), 1_000);
+        assert_eq!(apply_multiplier_bps(1_000, 12_500).unwrap(), 1_250);
+        assert_eq!(apply_multiplier_bps(999, 15_000).unwrap(), 1_498);
+        assert_eq!(apply_multiplier_bps(u64::MAX, 10_000).unwrap(), u64::MAX);
+        assert_eq!(apply_multiplier_bps(u64::MAX, 5_000).unwrap(), u64::MAX / 2);
+        assert!(apply_multiplier_bps(u64::MAX, 10_001).is_err());
+        assert!(apply_multiplier_bps(u64::MAX, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn checked_helpers() {
+        assert!(safe_add(u64::MAX, 1).is_err());
+        assert!(safe_sub(0, 1).is_err());
+        assert!(safe_mul(u64::MAX, 2).is_err());
+        assert_eq!(calculate_percentage(10_000, 250).unwrap(), 250);
+        assert!(calculate_percentage(u64::MAX, 2).is_err());
+    }
 }
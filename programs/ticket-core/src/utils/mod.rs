@@ -1,7 +1,9 @@
 pub mod validation;
 pub mod math;
 pub mod security;
+pub mod accounts;
 
 pub use validation::*;
 pub use math::*;
 pub use security::*;
+pub use accounts::*;
@@ -1,5 +1,6 @@
 //! Security utilities
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use crate::errors::TicketError;
 
 pub struct RateLimiter {
@@ -28,9 +29,12 @@ impl RateLimiter {
     }
 }
 
+/// Checks that at least `required` distinct members of `valid_signers` are in
+/// `signers`. A key listed twice only counts once.
 pub fn verify_multisig(signers: &[Pubkey], required: u8, valid_signers: &[Pubkey]) -> Result<()> {
     let valid_count = signers.iter()
-        .filter(|s| valid_signers.contains(s))
+        .enumerate()
+        .filter(|(i, s)| valid_signers.contains(s) && !signers[..*i].contains(s))
         .count();
         
     require!(
@@ -40,3 +44,104 @@ pub fn verify_multisig(signers: &[Pubkey], required: u8, valid_signers: &[Pubkey
     
     Ok(())
 }
+
+/// Whitelist leaf: keccak(wallet), or keccak(wallet || allocation) when the
+/// list assigns per-wallet allocations.
+pub fn whitelist_leaf(wallet: &Pubkey, allocation: Option<u8>) -> [u8; 32] {
+    match allocation {
+        Some(allocation) => keccak::hashv(&[wallet.as_ref(), &[allocation]]).0,
+        None => keccak::hashv(&[wallet.as_ref()]).0,
+    }
+}
+
+/// Verifies a merkle proof built with sorted-pair keccak hashing, so proofs
+/// need no left/right flags.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &node]).0
+        }
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[a, b]).0
+        } else {
+            keccak::hashv(&[b, a]).0
+        }
+    }
+
+    fn wallets() -> Vec<Pubkey> {
+        (1..=4u8).map(|i| Pubkey::new_from_array([i; 32])).collect()
+    }
+
+    /// Four-leaf tree; returns the root and each leaf's proof.
+    fn tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let proofs = vec![
+            vec![leaves[1], right],
+            vec![leaves[0], right],
+            vec![leaves[3], left],
+            vec![leaves[2], left],
+        ];
+        (hash_pair(&left, &right), proofs)
+    }
+
+    #[test]
+    fn valid_proofs_verify() {
+        let leaves: Vec<_> = wallets().iter().map(|w| whitelist_leaf(w, None)).collect();
+        let (root, proofs) = tree(&leaves);
+        for (leaf, proof) in leaves.iter().zip(&proofs) {
+            assert!(verify_merkle_proof(proof, &root, *leaf));
+        }
+    }
+
+    #[test]
+    fn tampered_sibling_fails() {
+        let leaves: Vec<_> = wallets().iter().map(|w| whitelist_leaf(w, None)).collect();
+        let (root, proofs) = tree(&leaves);
+        let mut proof = proofs[0].clone();
+        proof[1][0] ^= 1;
+        assert!(!verify_merkle_proof(&proof, &root, leaves[0]));
+    }
+
+    #[test]
+    fn wrong_leaf_fails() {
+        let wallets = wallets();
+        let leaves: Vec<_> = wallets.iter().map(|w| whitelist_leaf(w, Some(2))).collect();
+        let (root, proofs) = tree(&leaves);
+
+        assert!(verify_merkle_proof(&proofs[0], &root, whitelist_leaf(&wallets[0], Some(2))));
+        // Claiming no allocation, or a bigger one, on an allocation list
+        assert!(!verify_merkle_proof(&proofs[0], &root, whitelist_leaf(&wallets[0], None)));
+        assert!(!verify_merkle_proof(&proofs[0], &root, whitelist_leaf(&wallets[0], Some(3))));
+        // Someone else's proof
+        assert!(!verify_merkle_proof(&proofs[0], &root, leaves[2]));
+    }
+
+    #[test]
+    fn empty_proof_matches_only_the_root() {
+        let leaf = whitelist_leaf(&wallets()[0], None);
+        assert!(verify_merkle_proof(&[], &leaf, leaf));
+        assert!(!verify_merkle_proof(&[], &whitelist_leaf(&wallets()[1], None), leaf));
+    }
+
+    #[test]
+    fn sibling_order_does_not_matter() {
+        let (a, b) = (whitelist_leaf(&wallets()[0], None), whitelist_leaf(&wallets()[1], None));
+        assert_eq!(hash_pair(&a, &b), hash_pair(&b, &a));
+
+        let root = hash_pair(&a, &b);
+        assert!(verify_merkle_proof(&[b], &root, a));
+        assert!(verify_merkle_proof(&[a], &root, b));
+    }
+}
@@ -1,6 +1,6 @@
 //! Input validation utilities
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::TicketError};
+use crate::{constants::*, errors::TicketError, state::{Payee, PricingCurve, TicketTier, TicketTierConfig}, utils::math::*};
 
 pub fn validate_event_name(name: &str) -> Result<()> {
     require!(
@@ -18,6 +18,21 @@ pub fn validate_event_name(name: &str) -> Result<()> {
     Ok(())
 }
 
+pub fn validate_description(description: &str) -> Result<()> {
+    require!(!description.is_empty(), TicketError::DescriptionEmpty);
+    require!(
+        description.len() <= MAX_EVENT_DESCRIPTION_LEN,
+        TicketError::DescriptionTooLong
+    );
+    Ok(())
+}
+
+pub fn validate_venue(venue: &str) -> Result<()> {
+    require!(!venue.is_empty(), TicketError::VenueEmpty);
+    require!(venue.len() <= MAX_VENUE_NAME_LEN, TicketError::VenueTooLong);
+    Ok(())
+}
+
 pub fn validate_price_range(price: u64, min: u64, max: u64) -> Result<()> {
     require!(
         price >= min && price <= max,
@@ -48,3 +63,101 @@ pub fn validate_event_times(start_time: i64, end_time: i64) -> Result<()> {
     
     Ok(())
 }
+
+pub fn validate_tier_config(tier: &TicketTierConfig) -> Result<()> {
+    require!(
+        !tier.name.is_empty() && tier.name.len() <= MAX_TIER_NAME_LEN,
+        TicketError::InvalidTierName
+    );
+    require!(tier.price > 0, TicketError::InvalidTicketPrice);
+    require!(tier.total_supply > 0, TicketError::InvalidTicketCount);
+    if let (Some(min), Some(max)) = (tier.min_price, tier.max_price) {
+        require!(min <= max, TicketError::InvalidTierPriceBounds);
+    }
+    if let Some(curve) = &tier.pricing_curve {
+        validate_pricing_curve(curve)?;
+    }
+    Ok(())
+}
+
+/// Curves must never discount below the base price, never decrease as the
+/// tier sells, and stay within MAX_PRICE_MULTIPLIER_BPS at sell-out.
+pub fn validate_pricing_curve(curve: &PricingCurve) -> Result<()> {
+    match curve {
+        PricingCurve::StepTable { steps } => {
+            require!(steps.len() <= MAX_PRICE_STEPS, TicketError::InvalidPricingCurve);
+            let mut previous: Option<(u16, u32)> = None;
+            for step in steps {
+                require!(
+                    step.utilization_bps <= BPS_DENOMINATOR as u16
+                        && step.multiplier_bps >= BPS_DENOMINATOR as u32,
+                    TicketError::InvalidPricingCurve
+                );
+                if let Some((utilization, multiplier)) = previous {
+                    require!(
+                        step.utilization_bps > utilization && step.multiplier_bps >= multiplier,
+                        TicketError::InvalidPricingCurve
+                    );
+                }
+                previous = Some((step.utilization_bps, step.multiplier_bps));
+            }
+        },
+        PricingCurve::Linear { max_multiplier_bps } => {
+            require!(
+                *max_multiplier_bps >= BPS_DENOMINATOR as u32,
+                TicketError::InvalidPricingCurve
+            );
+        },
+        PricingCurve::Exponential { step_bps, .. } => {
+            require!(
+                *step_bps >= 100 && *step_bps <= BPS_DENOMINATOR as u16,
+                TicketError::InvalidPricingCurve
+            );
+        },
+    }
+
+    // Sell-out is the curve's maximum; overflow there means the curve is unusable
+    let max_multiplier = curve_multiplier_bps(curve, BPS_DENOMINATOR as u16)
+        .map_err(|_| TicketError::InvalidPricingCurve)?;
+    require!(
+        max_multiplier <= MAX_PRICE_MULTIPLIER_BPS,
+        TicketError::InvalidPricingCurve
+    );
+    Ok(())
+}
+
+/// Sum of tier supplies must fit within the event's total capacity.
+pub fn validate_tier_supply(tiers: &[TicketTier], total_tickets: u32) -> Result<()> {
+    let total: u64 = tiers.iter().map(|t| t.total_supply as u64).sum();
+    require!(
+        total <= total_tickets as u64,
+        TicketError::TierSupplyExceedsCapacity
+    );
+    Ok(())
+}
+
+/// A per-wallet cap, when set, must allow at least one ticket.
+pub fn validate_wallet_limit(max_tickets_per_wallet: Option<u16>) -> Result<()> {
+    require!(max_tickets_per_wallet != Some(0), TicketError::InvalidWalletLimit);
+    Ok(())
+}
+
+/// Payees must be unique and their shares must sum to exactly 100%.
+pub fn validate_payees(payees: &[Payee]) -> Result<()> {
+    if payees.is_empty() {
+        return Ok(());
+    }
+    require!(payees.len() <= MAX_PAYEES, TicketError::InvalidRevenueSplit);
+
+    let mut total: u32 = 0;
+    for (i, payee) in payees.iter().enumerate() {
+        require!(payee.basis_points > 0, TicketError::InvalidRevenueSplit);
+        require!(
+            !payees[..i].iter().any(|p| p.wallet == payee.wallet),
+            TicketError::InvalidRevenueSplit
+        );
+        total += payee.basis_points as u32;
+    }
+    require!(total == 10_000, TicketError::InvalidRevenueSplit);
+    Ok(())
+}